@@ -0,0 +1,87 @@
+//! Measures the per-frame cost of tracking many assets that are still
+//! loading.
+//!
+//! Run with `cargo bench --bench assets_progress --features assets`.
+
+use std::time::{Duration, Instant};
+
+use bevy::asset::LoadedFolder;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use iyes_progress::prelude::*;
+
+const HANDLES: usize = 5000;
+const WARMUP_FRAMES: u32 = 10;
+const FRAMES: u32 = 1000;
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Done,
+}
+
+/// Run frames with `HANDLES` assets that never finish loading, and return
+/// the average frame time. If `track` is false, the assets are loading, but
+/// not tracked, which gives us the baseline.
+fn frame_time(track: bool) -> Duration {
+    // Keep the state from completing, even without any assets.
+    let gate = ProgressEntryId::new();
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin));
+    app.init_state::<MyStates>();
+    app.add_plugins(
+        ProgressPlugin::<MyStates>::new()
+            .with_state_transition(MyStates::Loading, MyStates::Done)
+            .with_reservation(gate, 1)
+            .with_asset_tracking(),
+    );
+    app.finish();
+    app.cleanup();
+
+    let server = app.world().resource::<AssetServer>().clone();
+    let handles: Vec<Handle<LoadedFolder>> = (0..HANDLES)
+        .map(|_| {
+            server.add_async(std::future::pending::<
+                Result<LoadedFolder, std::io::Error>,
+            >())
+        })
+        .collect();
+    if track {
+        let mut loading =
+            app.world_mut().resource_mut::<AssetsLoading<MyStates>>();
+        loading.add_all(&handles);
+    }
+
+    for _ in 0..WARMUP_FRAMES {
+        app.update();
+    }
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        app.update();
+    }
+    let elapsed = start.elapsed() / FRAMES;
+
+    assert_eq!(
+        app.world().resource::<State<MyStates>>().get(),
+        &MyStates::Loading
+    );
+    let expected = if track { (0, HANDLES) } else { (0, 0) };
+    assert_eq!(
+        app.world().resource::<AssetsLoading<MyStates>>().counts(),
+        expected
+    );
+    elapsed
+}
+
+fn main() {
+    let baseline = frame_time(false);
+    let tracked = frame_time(true);
+    println!("{HANDLES} loading assets, average over {FRAMES} frames:");
+    println!("  not tracked: {baseline:?} per frame");
+    println!("  tracked:     {tracked:?} per frame");
+    println!(
+        "  tracking:    {:?} per frame",
+        tracked.saturating_sub(baseline)
+    );
+}
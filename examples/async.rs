@@ -13,13 +13,16 @@ fn main() {
         .init_state::<MyStates>()
         .add_plugins(
             ProgressPlugin::<MyStates>::new()
-                // Do not clear on enter.
-                // This is so that our `spawn_background_work` system can
-                // set the total without it being immediately lost.
-                .auto_clear(false, true)
                 .with_state_transition(MyStates::Loading, MyStates::Done),
         )
-        .add_systems(OnEnter(MyStates::Loading), spawn_background_work)
+        .add_systems(
+            OnEnter(MyStates::Loading),
+            // Progress is cleared when entering the loading state.
+            // Make sure we set up our entries after that happens,
+            // so they don't get immediately lost.
+            (spawn_background_work, spawn_background_work_shortcut)
+                .after(clear_global_progress::<MyStates>),
+        )
         .add_systems(OnEnter(MyStates::Done), move || {
             info!("Loading complete!");
         })
@@ -33,9 +36,7 @@ enum MyStates {
     Done,
 }
 
-fn spawn_background_work(
-    mut pt: ResMut<ProgressTracker<MyStates>>,
-) {
+fn spawn_background_work(mut pt: ResMut<ProgressTracker<MyStates>>) {
     // Create an entry in the progress tracker representing our
     // background work and a "sender handle" that we can give to
     // our thread to update the progress values.
@@ -56,3 +57,17 @@ fn spawn_background_work(
         sender.set_done(1);
     });
 }
+
+fn spawn_background_work_shortcut(mut pt: ResMut<ProgressTracker<MyStates>>) {
+    // The above pattern is common enough that there is a shortcut for it.
+    // This creates an entry, sets its total, and spawns a thread. The entry
+    // is marked as done when our closure returns.
+    let id = pt.spawn_tracked(10, |sender| {
+        for i in 1..10 {
+            std::thread::sleep(Duration::from_millis(300));
+            // We can still report intermediate progress, if we want to.
+            sender.set_done(i);
+        }
+    });
+    info!("Spawned background work with entry ID {:?}", id);
+}
@@ -0,0 +1,86 @@
+//! This example shows how to track a future, using
+//! `ProgressTracker::track_future`, for example to download data over the
+//! network.
+//!
+//! The future is spawned on Bevy's `AsyncComputeTaskPool`, and its entry is
+//! marked as done automatically when it completes. Compared to the
+//! `async_tasks` example, there is no need to set up the entry yourself.
+//!
+//! To keep the example self-contained, the "network" is simulated.
+
+use bevy::prelude::*;
+use bevy::tasks::futures_lite::future;
+use iyes_progress::prelude::*;
+
+/// The files we want to "download", and their size in chunks.
+const FILES: &[(&str, u32)] =
+    &[("level.dat", 40), ("textures.pak", 120), ("music.ogg", 80)];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<MyStates>()
+        .add_plugins(
+            ProgressPlugin::<MyStates>::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done),
+        )
+        .add_systems(
+            OnEnter(MyStates::Loading),
+            // Progress is cleared when entering the loading state.
+            // Make sure we set up our entries after that happens,
+            // so they don't get immediately lost.
+            start_downloads.after(clear_global_progress::<MyStates>),
+        )
+        .add_systems(Update, print_progress.run_if(in_state(MyStates::Loading)))
+        .add_systems(OnEnter(MyStates::Done), move || {
+            info!("All downloads complete!");
+        })
+        .run();
+}
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Done,
+}
+
+fn start_downloads(mut pt: ResMut<ProgressTracker<MyStates>>) {
+    for &(name, n_chunks) in FILES {
+        // The entry is created with `n_chunks` as its total. When the
+        // future returns, the entry is marked as done.
+        let id = pt.track_future(n_chunks, move |sender| async move {
+            let mut data = Vec::new();
+            for i in 0..n_chunks {
+                // If the user leaves the loading state, stop downloading.
+                if sender.is_cancelled() {
+                    return;
+                }
+                data.extend(download_chunk(name, i).await);
+                sender.add_done(1);
+            }
+            info!("Downloaded {} ({} bytes)", name, data.len());
+        });
+        pt.set_label(id, name);
+    }
+}
+
+/// Pretend to download a chunk of a file.
+///
+/// In a real app, this would use an HTTP client. Here, we do a bit of busy
+/// work and yield a few times, to simulate waiting for the network.
+async fn download_chunk(name: &str, i: u32) -> Vec<u8> {
+    let mut x = name.len() as u64 + i as u64;
+    for _ in 0..10 {
+        for j in 0..10_000 {
+            x = x.wrapping_mul(31).wrapping_add(j);
+        }
+        future::yield_now().await;
+    }
+    x.to_le_bytes().to_vec()
+}
+
+fn print_progress(pt: Res<ProgressTracker<MyStates>>) {
+    let progress = pt.get_global_progress();
+    info!("Downloading: {}/{}", progress.done, progress.total);
+}
@@ -0,0 +1,77 @@
+//! This example shows how to update progress from async tasks running on
+//! Bevy's task pools, instead of OS threads (see the `async` example).
+//!
+//! The same `ProgressSender` API is used in both cases.
+
+use bevy::prelude::*;
+use bevy::tasks::futures_lite::future;
+use bevy::tasks::AsyncComputeTaskPool;
+use iyes_progress::prelude::*;
+
+const N_TASKS: u32 = 4;
+const N_CHUNKS: u32 = 100;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<MyStates>()
+        .add_plugins(
+            ProgressPlugin::<MyStates>::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done),
+        )
+        .add_systems(
+            OnEnter(MyStates::Loading),
+            // Progress is cleared when entering the loading state.
+            // Make sure we set up our entries after that happens,
+            // so they don't get immediately lost.
+            spawn_background_work.after(clear_global_progress::<MyStates>),
+        )
+        .add_systems(OnEnter(MyStates::Done), move || {
+            info!("Loading complete!");
+        })
+        .run();
+}
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Done,
+}
+
+fn spawn_background_work(mut pt: ResMut<ProgressTracker<MyStates>>) {
+    let pool = AsyncComputeTaskPool::get();
+
+    for _ in 0..N_TASKS {
+        // Create an entry in the progress tracker representing our
+        // task and a "sender handle" that the task can use to update
+        // the progress values.
+        let sender = pt.new_async_entry();
+
+        // While we are still here and we have direct access to the
+        // progress tracker, we can directly update the values for the entry.
+        pt.set_total(sender.id(), N_CHUNKS);
+
+        pool.spawn(async move {
+            for _ in 0..N_CHUNKS {
+                // imagine we are doing some real work here, like
+                // decoding data we downloaded over the network...
+                let mut x = 0u64;
+                for i in 0..100_000 {
+                    x = x.wrapping_mul(31).wrapping_add(i);
+                }
+                std::hint::black_box(x);
+
+                // `iyes_progress` runs a system every bevy frame, which will
+                // actually apply the values we send to the entry in the
+                // progress tracker.
+                sender.add_done(1);
+
+                // Give other tasks a chance to run. If the task pool only
+                // has one thread, this is what keeps the app responsive.
+                future::yield_now().await;
+            }
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,60 @@
+//! This example shows how to report progress from observers, in response
+//! to triggered events, instead of from systems that poll every frame.
+
+use bevy::prelude::*;
+use iyes_progress::prelude::*;
+
+const N_ITEMS: u32 = 20;
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Done,
+}
+
+/// Imagine this event is triggered by something else in your game,
+/// whenever a piece of work completes.
+#[derive(Event)]
+struct ItemLoaded;
+
+fn main() {
+    // Create the ID of our entry up front, so that we can tell the plugin
+    // to reserve the expected total. This way, the state transition cannot
+    // happen before our observer has been triggered for the first time.
+    let items = ProgressEntryId::new();
+
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<MyStates>()
+        .add_plugins(
+            ProgressPlugin::<MyStates>::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(items, N_ITEMS),
+        )
+        .add_systems(Update, trigger_items.run_if(in_state(MyStates::Loading)))
+        .add_observer(
+            move |_trigger: Trigger<ItemLoaded>,
+                  tracker: Res<ProgressTracker<MyStates>>| {
+                tracker.add_done(items, 1);
+            },
+        )
+        .add_systems(OnEnter(MyStates::Done), || {
+            info!("Loading complete!");
+        })
+        .run();
+}
+
+// Pretend that an item finishes loading every 100ms.
+fn trigger_items(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: Local<Option<Timer>>,
+) {
+    let timer = timer
+        .get_or_insert_with(|| Timer::from_seconds(0.1, TimerMode::Repeating));
+    timer.tick(time.delta());
+    for _ in 0..timer.times_finished_this_tick() {
+        commands.trigger(ItemLoaded);
+    }
+}
@@ -0,0 +1,109 @@
+//! This example shows how to do phased loading within a single state.
+//!
+//! Some of our loading work (phase 2) depends on other work (phase 1)
+//! being complete first. Instead of adding run conditions to every system,
+//! we put the phase 2 systems in a system set, and configure the set to
+//! only run once the phase 1 entry is ready.
+
+use bevy::prelude::*;
+use iyes_progress::prelude::*;
+
+const N_CHUNKS: u32 = 120;
+const N_THINGS: u32 = 60;
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Done,
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct Phase2;
+
+/// The IDs of the progress entries representing each phase.
+#[derive(Resource, Clone, Copy)]
+struct LoadingPhases {
+    phase1: ProgressEntryId,
+    phase2: ProgressEntryId,
+}
+
+fn main() {
+    let phases = LoadingPhases {
+        phase1: ProgressEntryId::new(),
+        phase2: ProgressEntryId::new(),
+    };
+
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<MyStates>()
+        .add_plugins(
+            ProgressPlugin::<MyStates>::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done),
+        )
+        .insert_resource(phases)
+        // All systems in this set will wait for phase 1 to complete.
+        .configure_sets(
+            Update,
+            Phase2
+                .run_if(in_state(MyStates::Loading))
+                .run_if(entry_ready::<MyStates>(phases.phase1)),
+        )
+        .add_systems(
+            OnEnter(MyStates::Loading),
+            // Progress is cleared when entering the loading state.
+            // Make sure we set up our entries after that happens,
+            // so they don't get immediately lost.
+            setup_phases.after(clear_global_progress::<MyStates>),
+        )
+        .add_systems(
+            Update,
+            (
+                generate_map.run_if(in_state(MyStates::Loading)),
+                populate_map.in_set(Phase2),
+            ),
+        )
+        .add_systems(OnEnter(MyStates::Done), || {
+            info!("Loading complete!");
+        })
+        .run();
+}
+
+// Set the expected amount of work for both phases up front. Otherwise, the
+// phase 2 entry would not exist until its systems start running, and the
+// state transition could happen as soon as phase 1 is complete.
+fn setup_phases(
+    tracker: Res<ProgressTracker<MyStates>>,
+    phases: Res<LoadingPhases>,
+) {
+    tracker.set_total(phases.phase1, N_CHUNKS);
+    tracker.set_total(phases.phase2, N_THINGS);
+}
+
+// Phase 1: pretend that we are generating a map, one chunk per frame.
+fn generate_map(
+    tracker: Res<ProgressTracker<MyStates>>,
+    phases: Res<LoadingPhases>,
+    mut n_chunks: Local<u32>,
+) {
+    if *n_chunks < N_CHUNKS {
+        *n_chunks += 1;
+        tracker.set_done(phases.phase1, *n_chunks);
+        if *n_chunks == N_CHUNKS {
+            info!("Phase 1 (map generation) complete.");
+        }
+    }
+}
+
+// Phase 2: pretend that we are spawning things into the map.
+// This only starts running after phase 1 is complete.
+fn populate_map(
+    tracker: Res<ProgressTracker<MyStates>>,
+    phases: Res<LoadingPhases>,
+    mut n_things: Local<u32>,
+) {
+    if *n_things < N_THINGS {
+        *n_things += 1;
+        tracker.set_done(phases.phase2, *n_things);
+    }
+}
@@ -0,0 +1,93 @@
+//! This example shows how to report progress from a sub-app.
+//!
+//! The `ProgressTracker<S>` lives in the main world, so systems running in
+//! a sub-app cannot access it directly. Instead, we create a
+//! `ProgressSender` in the main world and hand it to the sub-app (using the
+//! sub-app's extract function). Sub-app systems can then use it to send
+//! progress updates, just like a background thread or async task would.
+
+use bevy::app::AppLabel;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use iyes_progress::prelude::*;
+
+const N_STEPS: u32 = 200;
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct MySubApp;
+
+/// Used to pass the sender from the main world to the sub-app.
+#[derive(Resource, Clone)]
+struct SubAppProgress(ProgressSender);
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins)
+        .init_state::<MyStates>()
+        .add_plugins(
+            ProgressPlugin::<MyStates>::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done),
+        )
+        .add_systems(
+            OnEnter(MyStates::Loading),
+            // Progress is cleared when entering the loading state.
+            // Make sure we set up our entries after that happens,
+            // so they don't get immediately lost.
+            setup_sub_app_progress.after(clear_global_progress::<MyStates>),
+        )
+        .add_systems(OnEnter(MyStates::Done), move || {
+            info!("Loading complete!");
+        });
+
+    let mut sub_app = SubApp::new();
+    sub_app.update_schedule = Some(Update.intern());
+    sub_app.add_systems(Update, sub_app_work);
+    // Runs every frame, after the main app has been updated.
+    sub_app.set_extract(|main_world, sub_world| {
+        if let Some(progress) = main_world.remove_resource::<SubAppProgress>() {
+            sub_world.insert_resource(progress);
+        }
+    });
+    app.insert_sub_app(MySubApp, sub_app);
+
+    app.run();
+}
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Done,
+}
+
+fn setup_sub_app_progress(
+    mut commands: Commands,
+    mut pt: ResMut<ProgressTracker<MyStates>>,
+) {
+    let sender = pt.new_async_entry();
+    // While we are still here and we have direct access to the
+    // progress tracker, we can directly update the values for the entry.
+    // This ensures the state transition does not happen before the sub-app
+    // has had a chance to start its work.
+    pt.set_total(sender.id(), N_STEPS);
+    commands.insert_resource(SubAppProgress(sender));
+}
+
+// This system runs in the sub-app.
+fn sub_app_work(
+    mut commands: Commands,
+    progress: Option<Res<SubAppProgress>>,
+    mut n_steps: Local<u32>,
+) {
+    let Some(progress) = progress else {
+        return;
+    };
+    // imagine we are doing some real work here...
+    *n_steps += 1;
+    progress.0.set_done(*n_steps);
+    if *n_steps == N_STEPS {
+        // We are done, no need to keep the sender around.
+        commands.remove_resource::<SubAppProgress>();
+        *n_steps = 0;
+    }
+}
@@ -0,0 +1,80 @@
+//! This example shows how to display a "loading summary" after the loading
+//! state is complete.
+//!
+//! Progress data is only cleared when entering the loading state (by
+//! default), so the final values are still available in the next state.
+
+use bevy::prelude::*;
+use iyes_progress::prelude::*;
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MyStates {
+    #[default]
+    Loading,
+    Summary,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<MyStates>()
+        .add_plugins(
+            ProgressPlugin::<MyStates>::new()
+                .with_state_transition(MyStates::Loading, MyStates::Summary)
+                // This is the default. Just making it explicit:
+                // clear when entering the loading state, but not on exit,
+                // so that we can still read the values afterwards.
+                .auto_clear(true, false),
+        )
+        .add_systems(Startup, setup_camera)
+        .add_systems(
+            Update,
+            (
+                fake_work_a.track_progress::<MyStates>(),
+                fake_work_b.track_progress::<MyStates>(),
+            )
+                .run_if(in_state(MyStates::Loading)),
+        )
+        .add_systems(OnEnter(MyStates::Summary), show_summary)
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+fn fake_work_a(mut n: Local<u32>) -> Progress {
+    *n = (*n + 1).min(482);
+    Progress::new(*n, 482)
+}
+
+fn fake_work_b(mut n: Local<u32>) -> HiddenProgress {
+    *n = (*n + 1).min(100);
+    HiddenProgress::new(*n, 100)
+}
+
+fn show_summary(mut commands: Commands, pt: Res<ProgressTracker<MyStates>>) {
+    let summary = pt.read(|view| {
+        let visible = view.get_global_progress();
+        let hidden = view.get_global_hidden_progress();
+        format!(
+            "Loading complete!\n\n\
+             Loaded {}/{} items (+{}/{} hidden), in {} entries.",
+            visible.done,
+            visible.total,
+            hidden.done,
+            hidden.total,
+            view.entry_count(),
+        )
+    });
+    info!("{}", summary);
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(16.0),
+            left: Val::Px(16.0),
+            ..Default::default()
+        },
+        Text::new(summary),
+    ));
+}
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
 
 use bevy_asset::prelude::*;
-use bevy_asset::{LoadState, UntypedAssetId};
+use bevy_asset::{
+    AssetPath, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
 use bevy_ecs::prelude::*;
-use bevy_state::state::FreelyMutableState;
+use bevy_state::prelude::*;
 use bevy_utils::HashSet;
 
 use crate::prelude::*;
@@ -16,6 +18,105 @@ use crate::prelude::*;
 #[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AssetsTrackProgress;
 
+/// System Set for registering the assets to track, when entering a
+/// progress-tracked state.
+///
+/// In the `OnEnter` schedules of progress-tracked states, this set is
+/// ordered after the progress data (and, if so configured, the
+/// [`AssetsLoading<S>`] resource) is cleared. Put your systems that add
+/// handles to [`AssetsLoading<S>`] in this set, so that their handles are
+/// not lost:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # fn load_my_assets() {}
+/// # let mut app = App::new();
+/// app.add_systems(
+///     OnEnter(MyStates::Loading),
+///     load_my_assets.in_set(RegisterAssetsSet),
+/// );
+/// ```
+///
+/// The assets are then checked for the first time in the same frame (in
+/// `PostUpdate`, see [`AssetsTrackProgress`]).
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegisterAssetsSet;
+
+/// Event sent when all the assets in [`AssetsLoading<S>`] have finished
+/// loading.
+///
+/// It is sent once, when [`AssetsLoading::is_ready`] becomes true after
+/// assets were added, even if other progress is still pending.
+/// Use it to start work that only depends on your assets, while the rest
+/// of the loading continues. It is sent again if more assets are added and
+/// finish loading later, or after [`assets_loading_reset`].
+#[derive(Event, Debug)]
+pub struct AssetsReady<S: ProgressContext> {
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for AssetsReady<S> {
+    fn default() -> Self {
+        Self { _pd: PhantomData }
+    }
+}
+
+/// Marker resource, present if the built-in asset tracking is enabled for
+/// states type `S`.
+///
+/// It is inserted by the [`ProgressPlugin`], if it was configured
+/// [`with_asset_tracking`](ProgressPlugin::with_asset_tracking). See also
+/// the [`asset_tracking_enabled`] run condition.
+#[derive(Resource)]
+pub struct AssetTrackingEnabled<S: ProgressContext> {
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for AssetTrackingEnabled<S> {
+    fn default() -> Self {
+        Self { _pd: PhantomData }
+    }
+}
+
+/// Run condition: is the built-in asset tracking enabled for states type
+/// `S`?
+///
+/// Use this for systems that add handles to [`AssetsLoading<S>`], if they
+/// may be used in apps where asset tracking is not enabled (the
+/// [`AssetsLoading<S>`] resource does not exist in that case).
+pub fn asset_tracking_enabled<S: ProgressContext>(
+    enabled: Option<Res<AssetTrackingEnabled<S>>>,
+) -> bool {
+    enabled.is_some()
+}
+
+/// What to do with an asset that loaded successfully, but some of whose
+/// dependencies failed to load.
+///
+/// Only relevant if [`AssetsLoading::track_dependencies`] is enabled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyFailurePolicy {
+    /// Treat it as if the asset itself failed to load. Whether it counts as
+    /// ready depends on [`AssetsLoading::allow_failures`].
+    #[default]
+    TreatAsRootFailed,
+    /// Count the asset as ready, ignoring the failed dependencies.
+    TreatAsReady,
+    /// Never count the asset as ready.
+    ///
+    /// Warning: this will stall your loading state, unless you handle the
+    /// failure yourself (check
+    /// [`dependency_failures`](AssetsLoading::dependency_failures)).
+    TreatAsPending,
+}
+
 /// Resource for tracking the loading of assets
 ///
 /// Note: to use this, you have to call
@@ -27,76 +128,335 @@ pub struct AssetsTrackProgress;
 ///
 /// Note that failed/errored/unloaded assets are counted as completed by
 /// default. Otherwise, your game could get stuck on the loading screen.
+/// If you disable [`allow_failures`](Self::allow_failures), any failures
+/// are reported as an error on the [`entry_id`](Self::entry_id) entry in
+/// the [`ProgressTracker<S>`] (see [`ProgressStore::has_errors`]).
 ///
 /// This resource should not be removed.
+///
+/// If you use another crate to manage your assets, such as
+/// `bevy_asset_loader`, you can add all of its handles at once using
+/// [`add_all`](Self::add_all). For example, with a `bevy_asset_loader`
+/// `AssetCollection`:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # struct MyAssets;
+/// # impl MyAssets {
+/// #     fn load(_world: &mut World) -> Vec<Handle<Image>> {
+/// #         Vec::new()
+/// #     }
+/// # }
+/// fn track_my_assets(world: &mut World) {
+///     // Start loading all the assets in the collection.
+///     let handles = MyAssets::load(world);
+///     world
+///         .resource_mut::<AssetsLoading<MyStates>>()
+///         .add_all(&handles);
+/// }
+/// ```
 #[derive(Resource)]
-pub struct AssetsLoading<S: FreelyMutableState> {
+pub struct AssetsLoading<S: ProgressContext> {
+    id: ProgressEntryId,
     pending: HashSet<UntypedAssetId>,
     done: HashSet<UntypedAssetId>,
+    failed: HashSet<UntypedAssetId>,
+    pending_paths: HashSet<AssetPath<'static>>,
+    done_paths: HashSet<AssetPath<'static>>,
+    deps_failed: HashSet<UntypedAssetId>,
+    had_pending: bool,
     /// Should we count assets that failed to load as progress?
     /// Warning: if this is false, you may freeze in your loading state
     /// if there are any errors. Defaults to true.
+    ///
+    /// If this is false, assets that failed are not checked again (unless
+    /// you later change this to true).
     pub allow_failures: bool,
     /// Should we check the status of asset dependencies?
     /// Defaults to true.
     pub track_dependencies: bool,
+    /// What to do if an asset's dependencies fail to load.
+    /// Defaults to [`DependencyFailurePolicy::TreatAsRootFailed`].
+    pub dependency_failure_policy: DependencyFailurePolicy,
     _pd: PhantomData<S>,
 }
 
-impl<S: FreelyMutableState> Default for AssetsLoading<S> {
+impl<S: ProgressContext> Default for AssetsLoading<S> {
     fn default() -> Self {
         AssetsLoading {
+            id: ProgressEntryId::new(),
             pending: Default::default(),
             done: Default::default(),
+            failed: Default::default(),
+            pending_paths: Default::default(),
+            done_paths: Default::default(),
+            deps_failed: Default::default(),
+            had_pending: false,
             allow_failures: true,
             track_dependencies: true,
+            dependency_failure_policy: Default::default(),
             _pd: PhantomData,
         }
     }
 }
 
-impl<S: FreelyMutableState> AssetsLoading<S> {
+impl<S: ProgressContext> AssetsLoading<S> {
+    fn reset(&mut self) {
+        // Keep the same entry ID, so we don't leave stale progress behind.
+        *self = AssetsLoading {
+            id: self.id,
+            ..Default::default()
+        };
+    }
+
+    /// Get the ID of the [`ProgressTracker<S>`] entry where the assets
+    /// progress is recorded.
+    pub fn entry_id(&self) -> ProgressEntryId {
+        self.id
+    }
+
     /// Add an asset to be tracked
     pub fn add<T: Into<UntypedAssetId>>(&mut self, handle: T) {
         let asset_id = handle.into();
-        if !self.done.contains(&asset_id) {
+        if !self.done.contains(&asset_id) && !self.failed.contains(&asset_id) {
             self.pending.insert(asset_id);
+            self.had_pending = true;
         }
     }
 
+    /// Add many assets to be tracked.
+    ///
+    /// Accepts anything iterable that yields handles or asset IDs.
+    pub fn add_all<T: Into<UntypedAssetId>>(
+        &mut self,
+        handles: impl IntoIterator<Item = T>,
+    ) {
+        for handle in handles {
+            self.add(handle);
+        }
+    }
+
+    /// Add an asset to be tracked, by its asset path.
+    ///
+    /// This is useful for labeled sub-assets (such as `"model.gltf#Mesh0"`),
+    /// when you do not have a handle to them. The path will be tracked until
+    /// the [`AssetServer`] knows about an asset with that path, and from then
+    /// on, that asset will be tracked like any other.
+    ///
+    /// The asset (or, for labeled sub-assets, the root asset) should already
+    /// be loading. If the root asset has finished loading and there is still
+    /// no asset with the given label, the label does not exist. That is
+    /// treated like a failed asset (see
+    /// [`allow_failures`](Self::allow_failures)).
+    ///
+    /// Note: if you have a handle to a labeled sub-asset (for example, from
+    /// `asset_server.load("model.gltf#Mesh0")`), you can simply
+    /// [`add`](Self::add) it. It will be tracked individually.
+    pub fn add_path(&mut self, path: impl Into<AssetPath<'static>>) {
+        let path = path.into();
+        if !self.done_paths.contains(&path) {
+            self.pending_paths.insert(path);
+            self.had_pending = true;
+        }
+    }
+
+    /// Get the number of assets that have finished loading, and the total
+    /// number of tracked assets: `(done, total)`.
+    ///
+    /// Assets added by path ([`add_path`](Self::add_path)) are included.
+    /// This is the same as what is recorded in the [`ProgressTracker<S>`].
+    pub fn counts(&self) -> (usize, usize) {
+        let done = self.done.len() + self.done_paths.len();
+        let pending =
+            self.pending.len() + self.failed.len() + self.pending_paths.len();
+        (done, done + pending)
+    }
+
+    /// Iterate over the tracked assets whose dependencies failed to load.
+    ///
+    /// Only available if [`track_dependencies`](Self::track_dependencies)
+    /// is enabled. Such assets are handled according to the
+    /// [`dependency_failure_policy`](Self::dependency_failure_policy).
+    pub fn dependency_failures(
+        &self,
+    ) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.deps_failed.iter().copied()
+    }
+
     /// Have all tracked assets finished loading?
     pub fn is_ready(&self) -> bool {
         self.pending.is_empty()
+            && self.failed.is_empty()
+            && self.pending_paths.is_empty()
+    }
+}
+
+/// Decide if an asset counts as ready, given its load state.
+///
+/// `deps_state` is the recursive dependency load state, if dependencies
+/// should be checked.
+///
+///  - `NotLoaded`: ready. Nothing is loading the asset (for example, it was
+///    not created by the asset server, or it was unloaded), so waiting for
+///    it would stall forever. This is deliberate, even though it means that
+///    an asset that was never loaded is not waited for.
+///  - `Loading`: pending.
+///  - `Loaded`: ready, unless we are checking dependencies and they are not
+///    all loaded yet (including if they have not started loading). Failed
+///    dependencies are handled according to the [`DependencyFailurePolicy`].
+///  - `Failed`: ready only if failures are allowed.
+///  - Any other state (in case Bevy adds more in the future): pending. We
+///    cannot know if such an asset will ever be usable, so it is safer to
+///    wait than to let the game run with a missing asset.
+fn is_asset_ready(
+    state: &LoadState,
+    deps_state: Option<&RecursiveDependencyLoadState>,
+    allow_failures: bool,
+    deps_policy: DependencyFailurePolicy,
+) -> bool {
+    if state.is_failed() {
+        allow_failures
+    } else if state.is_loading() {
+        false
+    } else if state.is_loaded() {
+        match deps_state {
+            None => true,
+            Some(deps) if deps.is_failed() => match deps_policy {
+                DependencyFailurePolicy::TreatAsRootFailed => allow_failures,
+                DependencyFailurePolicy::TreatAsReady => true,
+                DependencyFailurePolicy::TreatAsPending => false,
+            },
+            Some(deps) => deps.is_loaded(),
+        }
+    } else {
+        matches!(state, LoadState::NotLoaded)
     }
 }
 
-pub(crate) fn assets_progress<S: FreelyMutableState>(
+/// Make a system that waits for a single asset to load.
+///
+/// This is a lightweight alternative to [`AssetsLoading`], for when you only
+/// need to wait for one handle (for example, in small tools or tests). The
+/// system returns [`Progress`], so add it with
+/// [`track_progress`](ProgressReturningSystem::track_progress):
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # let mut app = App::new();
+/// # let asset_server = app.world().resource::<AssetServer>().clone();
+/// let handle: Handle<Image> = asset_server.load("logo.png");
+/// app.add_systems(
+///     Update,
+///     wait_for_asset(handle)
+///         .track_progress::<MyStates>()
+///         .run_if(in_state(MyStates::Loading)),
+/// );
+/// ```
+///
+/// The asset is considered ready in the same cases as with the default
+/// settings of [`AssetsLoading`]: when it and all of its dependencies have
+/// finished loading, or if anything failed to load (so that your game does
+/// not get stuck on the loading screen).
+pub fn wait_for_asset<A: Asset>(
+    handle: Handle<A>,
+) -> impl System<In = (), Out = Progress> {
+    IntoSystem::into_system(move |server: Res<AssetServer>| -> Progress {
+        let state = server.load_state(&handle);
+        let deps_state = state
+            .is_loaded()
+            .then(|| server.recursive_dependency_load_state(&handle));
+        is_asset_ready(
+            &state,
+            deps_state.as_ref(),
+            true,
+            DependencyFailurePolicy::default(),
+        )
+        .into()
+    })
+}
+
+pub(crate) fn assets_progress<S: ProgressContext>(
     mut loading: ResMut<AssetsLoading<S>>,
     server: Res<AssetServer>,
-) -> Progress {
+    tracker: Res<ProgressTracker<S>>,
+    mut evw: EventWriter<AssetsReady<S>>,
+) {
     let mut any_changed = false;
+    let mut n_failed = 0;
     {
         let loading = loading.bypass_change_detection();
-        loading.pending.retain(|aid| {
-            let loaded = server.load_state(*aid);
-            let ready = match loaded {
-                LoadState::NotLoaded => true,
-                LoadState::Loading => false,
-                LoadState::Loaded => {
-                    if loading.track_dependencies {
-                        let loaded_deps =
-                            server.recursive_dependency_load_state(*aid);
-                        if loading.allow_failures && loaded_deps.is_failed() {
-                            true
-                        } else {
-                            loaded_deps.is_loaded()
-                        }
-                    } else {
-                        true
-                    }
+        // Failures are final, so we only check those assets again if the
+        // failures have since become allowed.
+        if loading.allow_failures && !loading.failed.is_empty() {
+            let failed = std::mem::take(&mut loading.failed);
+            loading.done.extend(failed);
+            any_changed = true;
+        }
+        loading.pending_paths.retain(|path| {
+            if let Some(aid) = server.get_path_id(path) {
+                if !loading.done.contains(&aid)
+                    && !loading.failed.contains(&aid)
+                {
+                    loading.pending.insert(aid);
                 }
-                LoadState::Failed(_) => loading.allow_failures,
+                return false;
+            }
+            let root_loading = server
+                .get_path_id(path.without_label())
+                .is_some_and(|aid| server.load_state(aid).is_loading());
+            if !root_loading {
+                if loading.allow_failures {
+                    loading.done_paths.insert(path.clone());
+                    any_changed = true;
+                    return false;
+                }
+                n_failed += 1;
+            }
+            true
+        });
+        loading.pending.retain(|aid| {
+            // One lookup gives us both the asset's own state and that of
+            // its dependencies.
+            let (state, deps_state) = match server.get_load_states(*aid) {
+                Some((state, _, deps)) => (state, Some(deps)),
+                None => (LoadState::NotLoaded, None),
             };
+            let deps_state = deps_state
+                .filter(|_| loading.track_dependencies && state.is_loaded());
+            let deps_failed =
+                deps_state.as_ref().is_some_and(|d| d.is_failed());
+            if deps_failed {
+                loading.deps_failed.insert(*aid);
+            }
+            if !loading.allow_failures
+                && (state.is_failed()
+                    || (deps_failed
+                        && loading.dependency_failure_policy
+                            == DependencyFailurePolicy::TreatAsRootFailed))
+            {
+                loading.failed.insert(*aid);
+                return false;
+            }
+            let ready = is_asset_ready(
+                &state,
+                deps_state.as_ref(),
+                loading.allow_failures,
+                loading.dependency_failure_policy,
+            );
             if ready {
                 loading.done.insert(*aid);
                 any_changed = true;
@@ -107,23 +467,291 @@ pub(crate) fn assets_progress<S: FreelyMutableState>(
     if any_changed {
         loading.set_changed();
     }
+    n_failed += loading.failed.len();
 
-    Progress {
-        done: loading.done.len() as u32,
-        total: loading.done.len() as u32 + loading.pending.len() as u32,
+    if !loading.is_ready() {
+        loading.bypass_change_detection().had_pending = true;
+    } else if loading.had_pending {
+        loading.bypass_change_detection().had_pending = false;
+        evw.send_default();
+    }
+
+    let (done, total) = loading.counts();
+    tracker.set_progress(loading.id, done as u32, total as u32);
+
+    if loading.allow_failures || n_failed == 0 {
+        tracker.clear_error(loading.id);
+    } else {
+        tracker.set_error(
+            loading.id,
+            format!("{} asset(s) failed to load", n_failed),
+        );
+    }
+}
+
+/// Automatically track all assets of type `A` that the [`AssetServer`]
+/// loads while in a progress-tracked state.
+///
+/// This only sees assets once they have been loaded (`AssetEvent::Added`),
+/// so it only waits for their dependencies, not for the assets themselves.
+///
+/// This runs in every state, so that the events from outside of the
+/// progress-tracked states are consumed and ignored, instead of being
+/// picked up when such a state is entered. The events read in the frame
+/// when the state is entered were sent in the previous frame (before the
+/// state transition), so they are ignored too.
+pub(crate) fn auto_track_assets<S: ProgressContext, A: Asset>(
+    mut evr: EventReader<AssetEvent<A>>,
+    mut loading: ResMut<AssetsLoading<S>>,
+    server: Res<AssetServer>,
+    config: Res<StateTransitionConfig<S>>,
+    state: Res<State<S::State>>,
+) {
+    if !config.is_tracked(state.get()) || state.is_changed() {
+        evr.clear();
+        return;
+    }
+    for ev in evr.read() {
+        let AssetEvent::Added { id } = ev else {
+            continue;
+        };
+        // Ignore assets that were not loaded via the asset server
+        // (such as ones created from code).
+        if server.get_load_state(*id).is_some() {
+            loading.add(*id);
+        }
     }
 }
 
 /// This system clears the [`AssetsLoading<S>`] resource.
 ///
+/// The associated entry in the [`ProgressTracker<S>`] is also reset
+/// (to `0/0`, with no error) immediately, so that it does not report stale
+/// values until the assets are checked again.
+///
 /// This will be automatically added to the `OnEnter`/`OnExit`
 /// schedules of progress-tracked states, if so configured
 /// by the [`ProgressPlugin`].
 ///
+/// When exiting a progress-tracked state, a variant of this system that
+/// leaves the [`ProgressTracker<S>`] entry alone is used instead, so that
+/// the final progress values remain readable in the next state.
+///
 /// This `fn` is `pub` so you can order your systems around it.
 /// Or add other "clearing points" to your app.
-pub fn assets_loading_reset<S: FreelyMutableState>(
+pub fn assets_loading_reset<S: ProgressContext>(
     mut loading: ResMut<AssetsLoading<S>>,
+    tracker: Option<Res<ProgressTracker<S>>>,
 ) {
-    *loading = AssetsLoading::default();
+    loading.reset();
+    if let Some(tracker) = tracker {
+        if tracker.contains_id(loading.id) {
+            tracker.set_progress(loading.id, 0, 0);
+        }
+        tracker.clear_error(loading.id);
+    }
+}
+
+/// Like [`assets_loading_reset`], but leaves the [`ProgressTracker<S>`]
+/// entry alone. Used when exiting a progress-tracked state.
+pub(crate) fn assets_loading_reset_keep_progress<S: ProgressContext>(
+    mut loading: ResMut<AssetsLoading<S>>,
+) {
+    loading.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy_asset::{AssetLoadError, LoadedFolder};
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_state::prelude::*;
+
+    use super::*;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum MyStates {
+        #[default]
+        Loading,
+        Menu,
+        Done,
+    }
+
+    fn failed() -> Arc<AssetLoadError> {
+        Arc::new(AssetLoadError::AssetMetaReadError)
+    }
+
+    fn ready(state: LoadState, allow_failures: bool) -> bool {
+        is_asset_ready(
+            &state,
+            None,
+            allow_failures,
+            DependencyFailurePolicy::default(),
+        )
+    }
+
+    fn ready_with_deps(deps: RecursiveDependencyLoadState) -> bool {
+        is_asset_ready(
+            &LoadState::Loaded,
+            Some(&deps),
+            true,
+            DependencyFailurePolicy::default(),
+        )
+    }
+
+    #[test]
+    fn not_loaded_is_ready() {
+        assert!(ready(LoadState::NotLoaded, true));
+        assert!(ready(LoadState::NotLoaded, false));
+    }
+
+    #[test]
+    fn loading_is_pending() {
+        assert!(!ready(LoadState::Loading, true));
+        assert!(!ready(LoadState::Loading, false));
+    }
+
+    #[test]
+    fn loaded_is_ready() {
+        assert!(ready(LoadState::Loaded, true));
+        assert!(ready(LoadState::Loaded, false));
+    }
+
+    #[test]
+    fn failed_depends_on_allow_failures() {
+        assert!(ready(LoadState::Failed(failed()), true));
+        assert!(!ready(LoadState::Failed(failed()), false));
+    }
+
+    #[test]
+    fn loaded_waits_for_dependencies() {
+        // Dependencies that have not started loading are not known to be
+        // usable, so they are pending.
+        assert!(!ready_with_deps(RecursiveDependencyLoadState::NotLoaded));
+        assert!(!ready_with_deps(RecursiveDependencyLoadState::Loading));
+        assert!(ready_with_deps(RecursiveDependencyLoadState::Loaded));
+    }
+
+    fn ready_with_failed_deps(
+        allow_failures: bool,
+        policy: DependencyFailurePolicy,
+    ) -> bool {
+        is_asset_ready(
+            &LoadState::Loaded,
+            Some(&RecursiveDependencyLoadState::Failed(failed())),
+            allow_failures,
+            policy,
+        )
+    }
+
+    #[test]
+    fn failed_dependency_treat_as_root_failed() {
+        let policy = DependencyFailurePolicy::TreatAsRootFailed;
+        assert!(ready_with_failed_deps(true, policy));
+        assert!(!ready_with_failed_deps(false, policy));
+    }
+
+    #[test]
+    fn failed_dependency_treat_as_ready() {
+        let policy = DependencyFailurePolicy::TreatAsReady;
+        assert!(ready_with_failed_deps(true, policy));
+        assert!(ready_with_failed_deps(false, policy));
+    }
+
+    #[test]
+    fn failed_dependency_treat_as_pending() {
+        let policy = DependencyFailurePolicy::TreatAsPending;
+        assert!(!ready_with_failed_deps(true, policy));
+        assert!(!ready_with_failed_deps(false, policy));
+    }
+
+    #[test]
+    fn failed_dependency_ignored_without_tracking() {
+        // Without `track_dependencies`, no dependency state is passed in.
+        for policy in [
+            DependencyFailurePolicy::TreatAsRootFailed,
+            DependencyFailurePolicy::TreatAsReady,
+            DependencyFailurePolicy::TreatAsPending,
+        ] {
+            assert!(is_asset_ready(&LoadState::Loaded, None, false, policy));
+        }
+    }
+
+    #[test]
+    fn reset_clears_tracker_entry() {
+        let mut world = World::new();
+        world.init_resource::<ProgressTracker<MyStates>>();
+        world.init_resource::<AssetsLoading<MyStates>>();
+        let id = world.resource::<AssetsLoading<MyStates>>().entry_id();
+        let tracker = world.resource::<ProgressTracker<MyStates>>();
+        tracker.set_progress(id, 3, 5);
+        tracker.set_error(id, "1 asset(s) failed to load");
+
+        world
+            .run_system_once(assets_loading_reset::<MyStates>)
+            .unwrap();
+
+        let tracker = world.resource::<ProgressTracker<MyStates>>();
+        assert_eq!(tracker.get_progress(id), Progress::new(0, 0));
+        assert_eq!(tracker.get_error(id), None);
+        assert!(tracker.is_ready());
+        let loading = world.resource::<AssetsLoading<MyStates>>();
+        assert_eq!(loading.entry_id(), id);
+        assert_eq!(loading.counts(), (0, 0));
+    }
+
+    #[test]
+    fn reset_keep_progress_leaves_tracker_entry() {
+        let mut world = World::new();
+        world.init_resource::<ProgressTracker<MyStates>>();
+        world.init_resource::<AssetsLoading<MyStates>>();
+        let id = world.resource::<AssetsLoading<MyStates>>().entry_id();
+        world
+            .resource::<ProgressTracker<MyStates>>()
+            .set_progress(id, 5, 5);
+
+        world
+            .run_system_once(assets_loading_reset_keep_progress::<MyStates>)
+            .unwrap();
+
+        let tracker = world.resource::<ProgressTracker<MyStates>>();
+        assert_eq!(tracker.get_progress(id), Progress::new(5, 5));
+    }
+
+    #[test]
+    fn auto_track_ignores_assets_loaded_outside() {
+        // Keep the state from completing.
+        let gate = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Menu,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(gate, 1)
+                .with_asset_tracking()
+                .with_auto_track_assets::<LoadedFolder>(),
+        );
+        app.app_mut().add_plugins(AssetPlugin::default());
+        let server = app.app().world().resource::<AssetServer>().clone();
+        let count = |app: &ProgressTestApp<MyStates>| {
+            app.app()
+                .world()
+                .resource::<AssetsLoading<MyStates>>()
+                .counts()
+        };
+
+        // Loaded in the frame right before the state is entered.
+        let _outside = server.add(LoadedFolder { handles: vec![] });
+        app.step();
+        app.app_mut()
+            .world_mut()
+            .resource_mut::<NextState<MyStates>>()
+            .set(MyStates::Loading);
+        app.step().assert_state(MyStates::Loading);
+        assert_eq!(count(&app), (0, 0));
+
+        let _inside = server.add(LoadedFolder { handles: vec![] });
+        app.step_n(2).assert_state(MyStates::Loading);
+        assert_eq!(count(&app), (1, 1));
+    }
 }
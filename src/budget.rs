@@ -0,0 +1,46 @@
+//! Spreading work over multiple frames
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+
+/// Resource to keep the frame rate smooth while doing heavy work in
+/// progress-tracked systems.
+///
+/// Systems that process items of work in batches can ask how many items
+/// to process in the current frame, using
+/// [`ProgressEntry::take_budget`](crate::tracker::ProgressEntry::take_budget).
+/// Without this resource, they always get their full per-frame amount.
+/// If this resource is present and the previous frame took longer than
+/// [`target_frame_time`](Self::target_frame_time), the amount is reduced
+/// in proportion, so that the loading screen keeps animating smoothly.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # let mut app = App::new();
+/// app.insert_resource(ProgressBudget {
+///     target_frame_time: Duration::from_secs(1) / 30,
+/// });
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressBudget {
+    /// How long frames should take (at most) while work is being done.
+    pub target_frame_time: Duration,
+}
+
+impl ProgressBudget {
+    /// Scale down `per_frame`, given how long the previous frame took.
+    ///
+    /// Never returns zero if `per_frame` is not zero, so that work always
+    /// continues.
+    pub fn scale(&self, per_frame: u32, last_frame_time: Duration) -> u32 {
+        if last_frame_time <= self.target_frame_time {
+            return per_frame;
+        }
+        let ratio = self.target_frame_time.as_secs_f64()
+            / last_frame_time.as_secs_f64();
+        ((per_frame as f64 * ratio) as u32).clamp(per_frame.min(1), per_frame)
+    }
+}
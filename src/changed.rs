@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use crate::prelude::*;
+
+/// Resource that lets you use Bevy change detection to know when the
+/// overall progress has changed.
+///
+/// The [`ProgressTracker<S>`] is updated through shared access (`Res`, not
+/// `ResMut`), so Bevy change detection does not work on it. Instead, a
+/// system (in the same schedule where progress is checked, `Last` by
+/// default) compares the global progress values with the ones from the
+/// previous frame, and only touches this resource if they are different.
+///
+/// This allows you to only run your UI systems when something changed:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # let mut app = App::new();
+/// fn update_progress_bar(tracker: Res<ProgressTracker<MyStates>>) {
+///     let progress = tracker.get_global_progress();
+///     // ... update your UI ...
+/// }
+///
+/// app.add_systems(
+///     Update,
+///     update_progress_bar
+///         .run_if(resource_changed::<ProgressChanged<MyStates>>),
+/// );
+/// ```
+///
+/// Only the global visible and hidden progress are compared. Changes to
+/// the individual entries that do not affect the totals (or to things like
+/// status messages) are not detected.
+#[derive(Resource)]
+pub struct ProgressChanged<S: ProgressContext> {
+    visible: Progress,
+    hidden: HiddenProgress,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for ProgressChanged<S> {
+    fn default() -> Self {
+        Self {
+            visible: Progress::default(),
+            hidden: HiddenProgress::default(),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<S: ProgressContext> ProgressChanged<S> {
+    /// The global visible progress, as of the last change.
+    pub fn visible(&self) -> Progress {
+        self.visible
+    }
+
+    /// The global hidden progress, as of the last change.
+    pub fn hidden(&self) -> HiddenProgress {
+        self.hidden
+    }
+}
+
+pub(crate) fn detect_progress_changes<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+    mut changed: ResMut<ProgressChanged<S>>,
+) {
+    let visible = tracker.get_global_progress();
+    let hidden = tracker.get_global_hidden_progress();
+    if changed.visible != visible || changed.hidden != hidden {
+        changed.visible = visible;
+        changed.hidden = hidden;
+    }
+}
@@ -0,0 +1,65 @@
+use bevy_ecs::prelude::*;
+
+use crate::prelude::*;
+
+/// Run condition: is all the progress in the [`ProgressTracker<S>`] ready?
+///
+/// You can use this to create a "barrier": systems (or whole system sets)
+/// that should only run after all the currently-tracked work is complete.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// # struct MyPhase2Set;
+/// # let mut app = App::new();
+/// app.configure_sets(Update,
+///     MyPhase2Set
+///         .run_if(in_state(MyStates::Loading))
+///         .run_if(global_ready::<MyStates>)
+/// );
+/// ```
+pub fn global_ready<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+) -> bool {
+    tracker.is_ready()
+}
+
+/// Run condition: is the entry with the given [`ProgressEntryId`] ready?
+///
+/// Returns `false` if there is no such entry in the tracker.
+///
+/// Useful for phased loading within a single state, where some systems
+/// should wait until a specific piece of work is complete.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// # struct MyPhase2Set;
+/// # let mut app = App::new();
+/// let phase1 = ProgressEntryId::new();
+/// // ... report the progress of phase 1 using `phase1` ...
+/// app.configure_sets(Update,
+///     MyPhase2Set
+///         .run_if(in_state(MyStates::Loading))
+///         .run_if(entry_ready::<MyStates>(phase1))
+/// );
+/// ```
+pub fn entry_ready<S: ProgressContext>(
+    id: ProgressEntryId,
+) -> impl FnMut(Res<ProgressTracker<S>>) -> bool + Clone {
+    move |tracker: Res<ProgressTracker<S>>| tracker.is_id_ready(id)
+}
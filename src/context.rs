@@ -0,0 +1,121 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use bevy_state::state::States;
+
+/// Identifies a "progress context": an independent set of progress data
+/// (a [`ProgressTracker`] and everything else that goes with it), along
+/// with the states type it is tracked in.
+///
+/// All the generic types and functions in this crate take a progress
+/// context. Normally, that is simply your states type: every [`States`]
+/// type is a progress context for itself, so you can write
+/// `ProgressTracker<MyStates>`, `ProgressPlugin::<MyStates>` etc.
+///
+/// Progress can be tracked in any kind of states, including computed
+/// states and sub-states. Automatic state transitions (see
+/// [`ProgressPlugin::with_state_transition`]) require the states to be
+/// freely mutable (a [`FreelyMutableState`], as normal `#[derive(States)]`
+/// types are), because they are done via [`NextState`].
+///
+/// If you need more than one independent context in the same states (for
+/// example, "critical" loading that the state transition waits for and
+/// "background" prefetching that it doesn't), use [`Tagged`].
+///
+/// [`ProgressTracker`]: crate::tracker::ProgressTracker
+/// [`ProgressPlugin::with_state_transition`]: crate::plugin::ProgressPlugin::with_state_transition
+/// [`FreelyMutableState`]: bevy_state::state::FreelyMutableState
+/// [`NextState`]: bevy_state::state::NextState
+pub trait ProgressContext:
+    Clone + PartialEq + Eq + Hash + fmt::Debug + Send + Sync + 'static
+{
+    /// The states type that progress is tracked in.
+    type State: States;
+}
+
+impl<S: States> ProgressContext for S {
+    type State = S;
+}
+
+/// A progress context for states type `S`, distinguished by a tag type `T`.
+///
+/// Each tag gets its own [`ProgressTracker`] and is configured with its own
+/// [`ProgressPlugin`], completely independently of the other tags (and of
+/// plain `S`):
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     InGame,
+/// # }
+/// # fn load_level() -> Progress {
+/// #     Progress::default()
+/// # }
+/// # let mut app = App::new();
+/// struct Critical;
+/// struct Prefetch;
+///
+/// app.add_plugins((
+///     ProgressPlugin::<Tagged<MyStates, Critical>>::new()
+///         .with_state_transition(MyStates::Loading, MyStates::InGame),
+///     // Only track progress, without any state transition.
+///     ProgressPlugin::<Tagged<MyStates, Prefetch>>::new()
+///         .with_tracked_state(MyStates::Loading),
+/// ));
+/// app.add_systems(
+///     Update,
+///     load_level.track_progress::<Tagged<MyStates, Critical>>(),
+/// );
+/// ```
+///
+/// This type is only used as a marker. It cannot be constructed.
+///
+/// [`ProgressTracker`]: crate::tracker::ProgressTracker
+/// [`ProgressPlugin`]: crate::plugin::ProgressPlugin
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::TypePath))]
+pub struct Tagged<S, T> {
+    _pd: PhantomData<fn() -> (S, T)>,
+    _never: Never,
+}
+
+// Tagged is uninhabited.
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::TypePath))]
+enum Never {}
+
+impl<S: States, T: 'static> ProgressContext for Tagged<S, T> {
+    type State = S;
+}
+
+// Manual impls, so that they do not require anything of `S` and `T`.
+// They are needed to satisfy the bounds on `ProgressContext`.
+
+impl<S, T> fmt::Debug for Tagged<S, T> {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self._never {}
+    }
+}
+
+impl<S, T> Clone for Tagged<S, T> {
+    fn clone(&self) -> Self {
+        match self._never {}
+    }
+}
+
+impl<S, T> PartialEq for Tagged<S, T> {
+    fn eq(&self, _: &Self) -> bool {
+        match self._never {}
+    }
+}
+
+impl<S, T> Eq for Tagged<S, T> {}
+
+impl<S, T> Hash for Tagged<S, T> {
+    fn hash<H: Hasher>(&self, _: &mut H) {
+        match self._never {}
+    }
+}
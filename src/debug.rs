@@ -1,39 +1,51 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
 use bevy_ecs::prelude::*;
 use bevy_log::prelude::*;
-use bevy_state::state::{FreelyMutableState, State};
+use bevy_state::state::State;
+use bevy_time::prelude::*;
 
+use crate::context::ProgressContext;
 use crate::state::*;
-use crate::tracker::ProgressTracker;
+use crate::tracker::{ProgressEntryId, ProgressTracker};
 
 /// Use this resource to control the logging of progress values every frame.
 ///
 /// The log messages are at TRACE level.
 ///
+/// Additionally, a summary can be logged (at INFO level) once all the
+/// progress in a state is complete, with how long it took (if the app has
+/// `Time<Virtual>`, from Bevy's `TimePlugin`).
+///
 /// Enabled by default. Only available if the `debug` cargo feature is enabled.
 #[derive(Resource)]
 pub struct ProgressDebug {
     /// If true, print trace messages.
     pub enabled: bool,
+    /// If true, print a summary when all progress is complete.
+    pub summary: bool,
 }
 
 impl Default for ProgressDebug {
     fn default() -> Self {
-        Self { enabled: true }
+        Self {
+            enabled: true,
+            summary: true,
+        }
     }
 }
 
-pub(crate) fn rc_debug_progress<S: FreelyMutableState>(
+pub(crate) fn rc_debug_progress<S: ProgressContext>(
     cfg_debug: Option<Res<ProgressDebug>>,
     cfg_state: Res<StateTransitionConfig<S>>,
-    state: Res<State<S>>,
+    state: Res<State<S::State>>,
 ) -> bool {
     cfg_debug.map(|cfg| cfg.enabled).unwrap_or(false)
-        && cfg_state.map_from_to.contains_key(state.get())
+        && cfg_state.is_tracked(state.get())
 }
 
-pub(crate) fn debug_progress<S: FreelyMutableState>(
-    pt: Res<ProgressTracker<S>>,
-) {
+pub(crate) fn debug_progress<S: ProgressContext>(pt: Res<ProgressTracker<S>>) {
     let visible = pt.get_global_progress();
     let hidden = pt.get_global_hidden_progress().0;
     let full = pt.get_global_combined_progress();
@@ -47,3 +59,276 @@ pub(crate) fn debug_progress<S: FreelyMutableState>(
         full.total,
     );
 }
+
+/// System that logs every entry in the [`ProgressTracker<S>`], one per line
+/// (at INFO level).
+///
+/// Useful for finding out what is holding things up, when your loading
+/// seems to be stuck. It is not added to your app automatically. Add it
+/// with a run condition of your choice, for example on a key press:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # use bevy::input::common_conditions::input_just_pressed;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # let mut app = App::new();
+/// app.add_systems(
+///     Update,
+///     dump_progress_entries::<MyStates>
+///         .run_if(input_just_pressed(KeyCode::F12)),
+/// );
+/// ```
+///
+/// Only available if the `debug` cargo feature is enabled.
+pub fn dump_progress_entries<S: ProgressContext>(pt: Res<ProgressTracker<S>>) {
+    pt.read(|view| {
+        info!(
+            "Progress entries: {} (ready: {})",
+            view.entry_count(),
+            view.is_ready(),
+        );
+        for (id, visible, hidden) in view.entries() {
+            let ready = if view.is_id_ready(id) {
+                "ready"
+            } else {
+                "pending"
+            };
+            let mut line = format!(
+                "  {:?}{}: Visible: {}/{}, Hidden: {}/{}, {}",
+                id,
+                view.get_label(id)
+                    .map(|l| format!(" {:?}", l))
+                    .unwrap_or_default(),
+                visible.done,
+                visible.total,
+                hidden.done,
+                hidden.total,
+                ready,
+            );
+            if let Some(status) = view.get_status(id) {
+                line += &format!(", status: {:?}", status);
+            }
+            if let Some(error) = view.get_error(id) {
+                line += &format!(", error: {:?}", error);
+            }
+            info!("{}", line);
+        }
+    });
+}
+
+pub(crate) struct StateVisit {
+    started: Duration,
+    logged: bool,
+}
+
+/// Log a summary (once per visit to a state) when all progress completes.
+pub(crate) fn log_completion_summary<S: ProgressContext>(
+    cfg_debug: Option<Res<ProgressDebug>>,
+    time: Option<Res<Time<Virtual>>>,
+    state: Res<State<S::State>>,
+    pt: Res<ProgressTracker<S>>,
+    #[cfg(feature = "assets")] assets: Option<
+        Res<crate::assets::AssetsLoading<S>>,
+    >,
+    mut visit: Local<Option<StateVisit>>,
+) {
+    let now = time.as_ref().map(|t| t.elapsed());
+    let visit = match &mut *visit {
+        // This system only runs in progress-tracked states, so if the state
+        // has changed since our last run, we must have (re-)entered one.
+        Some(visit) if !state.is_changed() => visit,
+        visit => visit.insert(StateVisit {
+            started: now.unwrap_or_default(),
+            logged: false,
+        }),
+    };
+    if visit.logged || !cfg_debug.is_some_and(|cfg| cfg.summary) {
+        return;
+    }
+    let Some((n_entries, visible, hidden)) = pt.read(|view| {
+        view.is_ready().then(|| {
+            (
+                view.entry_count(),
+                view.get_global_progress(),
+                view.get_global_hidden_progress(),
+            )
+        })
+    }) else {
+        return;
+    };
+    visit.logged = true;
+    #[allow(unused_mut)]
+    let mut breakdown = format!(
+        "{} entries, visible {}/{}, hidden {}/{}",
+        n_entries, visible.done, visible.total, hidden.done, hidden.total,
+    );
+    #[cfg(feature = "assets")]
+    if let Some(assets) = assets {
+        breakdown += &format!(", {} assets", assets.counts().1);
+    }
+    // Without time (no `TimePlugin`), we cannot tell how long it took.
+    match now {
+        Some(now) => info!(
+            "Progress in state {:?} complete in {:.2}s: {}",
+            state.get(),
+            now.saturating_sub(visit.started).as_secs_f32(),
+            breakdown,
+        ),
+        None => info!(
+            "Progress in state {:?} complete: {}",
+            state.get(),
+            breakdown,
+        ),
+    }
+}
+
+/// Warn (once) if more work has been reported as done than expected.
+///
+/// This usually means that some work is being counted twice. It would
+/// make progress bars render past 100%.
+pub(crate) fn warn_progress_overflow<S: ProgressContext>(
+    pt: Res<ProgressTracker<S>>,
+    mut warned: Local<bool>,
+) {
+    let overflow = pt.read(|view| {
+        let visible = view.get_global_progress();
+        let hidden = view.get_global_hidden_progress();
+        // Cheap check first, only look at the entries if needed.
+        if visible.done <= visible.total && hidden.done <= hidden.total {
+            return None;
+        }
+        let entries: Vec<_> = view
+            .entries()
+            .filter(|(_, v, h)| v.done > v.total || h.done > h.total)
+            .map(|(id, v, h)| match view.get_label(id) {
+                Some(label) => format!("{:?} ({:?}, {:?})", label, v, h),
+                None => format!("{:?} ({:?}, {:?})", id, v, h),
+            })
+            .collect();
+        Some((visible, hidden, entries))
+    });
+    let Some((visible, hidden, entries)) = overflow else {
+        *warned = false;
+        return;
+    };
+    if *warned {
+        return;
+    }
+    *warned = true;
+    warn!(
+        "Progress exceeds 100%! Visible: {}/{}, Hidden: {}/{}. \
+         Entries with more done than total: [{}]",
+        visible.done,
+        visible.total,
+        hidden.done,
+        hidden.total,
+        entries.join(", "),
+    );
+}
+
+/// How simulated progress should advance over time.
+///
+/// See [`ProgressSimulator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressSimulation {
+    /// Advance smoothly, reaching 100% after the given duration.
+    Linear(Duration),
+    /// Advance in a number of equal jumps, reaching 100% after the given
+    /// duration.
+    Stepped(Duration, u32),
+}
+
+impl ProgressSimulation {
+    /// Get the simulated fraction (`0.0..=1.0`), after some time has passed.
+    pub fn fraction(&self, elapsed: Duration) -> f32 {
+        let (duration, steps) = match *self {
+            ProgressSimulation::Linear(duration) => (duration, None),
+            ProgressSimulation::Stepped(duration, steps) => {
+                (duration, Some(steps.max(1)))
+            }
+        };
+        if duration.is_zero() {
+            return 1.0;
+        }
+        let t = (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.0);
+        match steps {
+            Some(steps) => (t * steps as f32).floor() / steps as f32,
+            None => t,
+        }
+    }
+}
+
+/// Resource for simulating fake progress, to help develop your loading UI.
+///
+/// With this, you can see what your UI looks like at various levels of
+/// progress, without having to wire up your real loading code. The
+/// simulated progress is reported as an additional (visible) entry in the
+/// [`ProgressTracker<S>`]. It starts over every time the tracker is
+/// cleared. Time is measured in virtual time (`Time<Virtual>`), so if the
+/// app does not have it, the simulated progress does not advance.
+///
+/// This resource is only available if the [`ProgressPlugin`] was configured
+/// [`with_simulation`](crate::plugin::ProgressPlugin::with_simulation).
+/// Only available if the `debug` cargo feature is enabled.
+///
+/// [`ProgressPlugin`]: crate::plugin::ProgressPlugin
+#[derive(Resource)]
+pub struct ProgressSimulator<S: ProgressContext> {
+    /// How the progress should advance.
+    pub simulation: ProgressSimulation,
+    id: ProgressEntryId,
+    elapsed: Duration,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> ProgressSimulator<S> {
+    /// Units of work that the simulated progress is split into.
+    const TOTAL: u32 = 1000;
+
+    /// Create a new simulator.
+    pub fn new(simulation: ProgressSimulation) -> Self {
+        Self {
+            simulation,
+            id: ProgressEntryId::new(),
+            elapsed: Duration::ZERO,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get the ID of the entry used to report the simulated progress.
+    pub fn entry_id(&self) -> ProgressEntryId {
+        self.id
+    }
+
+    /// Get the current simulated fraction (`0.0..=1.0`).
+    pub fn fraction(&self) -> f32 {
+        self.simulation.fraction(self.elapsed)
+    }
+
+    /// Start over from zero.
+    pub fn restart(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+}
+
+pub(crate) fn simulate_progress<S: ProgressContext>(
+    time: Option<Res<Time<Virtual>>>,
+    tracker: Res<ProgressTracker<S>>,
+    mut evr_cleared: EventReader<ProgressCleared<S>>,
+    mut simulator: ResMut<ProgressSimulator<S>>,
+) {
+    if evr_cleared.read().count() > 0 {
+        simulator.restart();
+    } else if let Some(time) = time {
+        simulator.elapsed += time.delta();
+    }
+    let total = ProgressSimulator::<S>::TOTAL;
+    let done = (simulator.fraction() * total as f32) as u32;
+    tracker.set_progress(simulator.id, done, total);
+}
@@ -0,0 +1,69 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+
+use crate::prelude::*;
+
+/// The visible progress fraction that should be displayed to the user.
+///
+/// This resource is only available if the [`ProgressPlugin`] was configured
+/// [`with_max_fill_rate`](ProgressPlugin::with_max_fill_rate).
+///
+/// The displayed fraction follows the actual progress (as returned by
+/// [`ProgressStore::get_displayed_progress`]), but is not allowed to
+/// increase faster than the configured rate. The state transition will wait
+/// until the displayed fraction reaches `1.0`. If the app has no
+/// `Time<Virtual>` resource, the limit is not applied.
+///
+/// Use this to drive your progress bar, instead of the values from the
+/// [`ProgressTracker<S>`].
+#[derive(Resource)]
+pub struct DisplayedProgress<S: ProgressContext> {
+    fraction: f32,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for DisplayedProgress<S> {
+    fn default() -> Self {
+        Self {
+            fraction: 0.0,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<S: ProgressContext> DisplayedProgress<S> {
+    /// Get the fraction to display, in the `0.0..=1.0` range.
+    pub fn fraction(&self) -> f32 {
+        self.fraction
+    }
+
+    /// Has the displayed progress reached 100%?
+    pub fn is_ready(&self) -> bool {
+        self.fraction >= 1.0
+    }
+}
+
+pub(crate) fn reset_displayed_progress<S: ProgressContext>(
+    mut displayed: ResMut<DisplayedProgress<S>>,
+) {
+    displayed.fraction = 0.0;
+}
+
+pub(crate) fn update_displayed_progress<S: ProgressContext>(
+    time: Option<Res<Time<Virtual>>>,
+    config: Res<StateTransitionConfig<S>>,
+    tracker: Res<ProgressTracker<S>>,
+    mut displayed: ResMut<DisplayedProgress<S>>,
+) {
+    let Some(rate) = config.max_fill_rate else {
+        return;
+    };
+    let target = tracker.get_displayed_progress().fraction().min(1.0);
+    // Without time, there is nothing to limit the rate by.
+    displayed.fraction = match time {
+        Some(time) => target.min(displayed.fraction + rate * time.delta_secs()),
+        None => target,
+    };
+}
@@ -1,21 +1,41 @@
 use std::marker::PhantomData;
 
+use bevy_ecs::component::ComponentId;
 use bevy_ecs::prelude::*;
-use bevy_state::state::FreelyMutableState;
+use bevy_ecs::world::DeferredWorld;
 
 use crate::prelude::*;
 
+/// System Set for summing up the progress stored in [`ProgressEntity`]
+/// components.
+///
+/// If your systems modify [`ProgressEntity`] components in the same schedule
+/// where the sum happens (`PostUpdate`, by default; see
+/// [`entity_sum_in`](ProgressPlugin::entity_sum_in)), order them before
+/// this set, so that the new values are tracked in the same frame.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntityProgressSet;
+
 /// Component to store progress on an entity.
 ///
 /// This is yet another way to report/store progress. You can insert
-/// this component on your entities. A system (in [`PostUpdate`]) will
-/// sum up all the values and track that sum in the [`ProgressTracker<S>`].
+/// this component on your entities. A system (in [`PostUpdate`], by
+/// default, in the [`EntityProgressSet`]) will sum up all the values and
+/// track that sum in the [`ProgressTracker<S>`].
 ///
 /// Note: the values from individual instances of this component are not
 /// copied/replicated in the [`ProgressTracker`]. Only the total sum is
 /// tracked. If you despawn your entity, any progress that was stored on it
 /// will be lost.
 ///
+/// If you want to know how much each individual entity has contributed,
+/// enable [`with_entity_entries`](ProgressPlugin::with_entity_entries) when
+/// creating your [`ProgressPlugin`]. Then, every instance of this component
+/// gets its own [`ProgressEntryId`] (see [`entry_id`](Self::entry_id)) and
+/// its values are tracked as a separate entry in the [`ProgressTracker`].
+/// The entry is removed when the component is removed or the entity is
+/// despawned.
+///
 /// ```rust
 /// commands.spawn((
 ///     ProgressEntity::<MyStates>::new()
@@ -25,26 +45,30 @@ use crate::prelude::*;
 ///     // ... other components
 /// ));
 /// ```
+///
+/// [`PostUpdate`]: bevy_app::PostUpdate
 #[derive(Component, Debug, Clone, PartialEq, Eq)]
-pub struct ProgressEntity<S: FreelyMutableState> {
+pub struct ProgressEntity<S: ProgressContext> {
     /// The visible progress associated with the entity.
     pub visible: Progress,
     /// The hidden progress associated with the entity.
     pub hidden: HiddenProgress,
+    entry_id: Option<ProgressEntryId>,
     _pd: PhantomData<S>,
 }
 
-impl<S: FreelyMutableState> Default for ProgressEntity<S> {
+impl<S: ProgressContext> Default for ProgressEntity<S> {
     fn default() -> Self {
         Self {
             visible: Progress::default(),
             hidden: HiddenProgress::default(),
+            entry_id: None,
             _pd: PhantomData,
         }
     }
 }
 
-impl<S: FreelyMutableState> ProgressEntity<S> {
+impl<S: ProgressContext> ProgressEntity<S> {
     /// The same as `Default::default()`.
     pub fn new() -> Self {
         Default::default()
@@ -63,17 +87,64 @@ impl<S: FreelyMutableState> ProgressEntity<S> {
         self.hidden.total = total;
         self
     }
+
+    /// Get the [`ProgressEntryId`] used to track this entity's progress.
+    ///
+    /// Only available if the [`ProgressPlugin`] was configured
+    /// [`with_entity_entries`](ProgressPlugin::with_entity_entries), and only
+    /// after the component has been inserted on an entity. Otherwise, `None`.
+    pub fn entry_id(&self) -> Option<ProgressEntryId> {
+        self.entry_id
+    }
 }
 
-pub(crate) fn apply_progress_from_entities<S: FreelyMutableState>(
+pub(crate) fn register_entity_entry_hooks<S: ProgressContext>(
+    world: &mut World,
+) {
+    world
+        .register_component_hooks::<ProgressEntity<S>>()
+        .on_insert(entity_entry_on_insert::<S>)
+        .on_replace(entity_entry_on_replace::<S>);
+}
+
+fn entity_entry_on_insert<S: ProgressContext>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    if let Some(mut pe) = world.get_mut::<ProgressEntity<S>>(entity) {
+        pe.entry_id = Some(ProgressEntryId::new());
+    }
+}
+
+fn entity_entry_on_replace<S: ProgressContext>(
+    world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let Some(id) = world
+        .get::<ProgressEntity<S>>(entity)
+        .and_then(|pe| pe.entry_id)
+    else {
+        return;
+    };
+    if let Some(tracker) = world.get_resource::<ProgressTracker<S>>() {
+        tracker.remove_entry(id);
+    }
+}
+
+pub(crate) fn apply_progress_from_entities<S: ProgressContext>(
     tracker: Res<ProgressTracker<S>>,
     q: Query<&ProgressEntity<S>>,
 ) {
-    let sum = q.iter().fold(
-        (Progress::default(), HiddenProgress::default()),
-        |sum, pfs| {
-            (sum.0 + pfs.visible, sum.1 + pfs.hidden)
-        },
-    );
+    let mut sum = (Progress::default(), HiddenProgress::default());
+    for pfs in q.iter() {
+        if let Some(id) = pfs.entry_id {
+            tracker.set_all(id, pfs.visible, pfs.hidden);
+        } else {
+            sum.0 = sum.0.saturating_add(pfs.visible);
+            sum.1 = sum.1.saturating_add(pfs.hidden);
+        }
+    }
     tracker.set_sum_entities(sum.0, sum.1);
 }
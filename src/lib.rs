@@ -35,6 +35,13 @@
 //!      running after they return full progress.
 //!  - Manually, by creating a [`ProgressEntryId`] and updating the values
 //!    stored in the [`ProgressTracker<S>`] resource.
+//!
+//! If your app only has one states type with progress tracking, you can use
+//! the [`define_progress_state!`] macro to generate non-generic type aliases.
+//!
+//! The [`global_ready`] and [`entry_ready`] run conditions let you make
+//! some of your systems wait until other work is complete (for example,
+//! for phased loading within a single state).
 
 #![warn(missing_docs)]
 
@@ -42,16 +49,29 @@
 pub mod prelude {
     #[cfg(feature = "assets")]
     pub use crate::assets::*;
+    pub use crate::budget::*;
+    pub use crate::changed::*;
+    pub use crate::condition::*;
+    pub use crate::context::*;
     #[cfg(feature = "debug")]
     pub use crate::debug::*;
+    pub use crate::display::*;
     pub use crate::entity::*;
+    pub use crate::phase::*;
     pub use crate::plugin::*;
     pub use crate::progress::*;
+    #[cfg(feature = "scene")]
+    pub use crate::scene::*;
     #[cfg(feature = "async")]
     pub use crate::send::*;
+    pub use crate::snapshot::*;
     pub use crate::state::*;
     pub use crate::system::*;
+    #[cfg(any(test, feature = "test-util"))]
+    pub use crate::test_util::*;
     pub use crate::tracker::*;
+    #[cfg(feature = "ui")]
+    pub use crate::ui::*;
     pub use crate::utils::*;
 }
 
@@ -59,14 +79,28 @@ pub use crate::prelude::*;
 
 #[cfg(feature = "assets")]
 mod assets;
+mod budget;
+mod changed;
+mod condition;
+mod context;
 #[cfg(feature = "debug")]
 mod debug;
+mod display;
 mod entity;
+mod macros;
+mod phase;
 mod plugin;
 mod progress;
+#[cfg(feature = "scene")]
+mod scene;
 #[cfg(feature = "async")]
 mod send;
+mod snapshot;
 mod state;
 mod system;
+#[cfg(any(test, feature = "test-util"))]
+mod test_util;
 mod tracker;
+#[cfg(feature = "ui")]
+mod ui;
 mod utils;
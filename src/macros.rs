@@ -0,0 +1,80 @@
+/// Generate non-generic type aliases for a specific states type.
+///
+/// Every API in this crate is generic over your states type, which can be
+/// verbose if your app only has one states type with progress tracking.
+/// This macro generates a module containing type aliases for it.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// #[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// enum MyStates {
+///     #[default]
+///     Loading,
+///     Done,
+/// }
+///
+/// iyes_progress::define_progress_state!(pub mod loading: MyStates);
+///
+/// fn my_system(progress: loading::ProgressEntry) {
+///     // ...
+/// }
+///
+/// fn my_other_system(tracker: Res<loading::ProgressTracker>) {
+///     // ...
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The module contains aliases (with the same names as the generic types)
+/// for: `ProgressPlugin`, `ProgressTracker`, `ProgressEntry`,
+/// `ProgressEntity`, `StateTransitionConfig`, `DisplayedProgress`,
+/// `ProgressCleared`, and (with the `assets` cargo feature) `AssetsLoading`.
+///
+/// Note: if you also glob-import the prelude of this crate, importing the
+/// module with a glob (`use loading::*`) would make the names ambiguous.
+/// Refer to the aliases via the module name, or import them individually.
+#[macro_export]
+macro_rules! define_progress_state {
+    ($vis:vis mod $name:ident : $state:ty) => {
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Alias for `ProgressPlugin` with our states type.
+            pub type ProgressPlugin = $crate::ProgressPlugin<$state>;
+            /// Alias for `ProgressTracker` with our states type.
+            pub type ProgressTracker = $crate::ProgressTracker<$state>;
+            /// Alias for `ProgressEntry` with our states type.
+            pub type ProgressEntry<'w, 's> =
+                $crate::ProgressEntry<'w, 's, $state>;
+            /// Alias for `ProgressEntity` with our states type.
+            pub type ProgressEntity = $crate::ProgressEntity<$state>;
+            /// Alias for `StateTransitionConfig` with our states type.
+            pub type StateTransitionConfig =
+                $crate::StateTransitionConfig<$state>;
+            /// Alias for `DisplayedProgress` with our states type.
+            pub type DisplayedProgress = $crate::DisplayedProgress<$state>;
+            /// Alias for `ProgressCleared` with our states type.
+            pub type ProgressCleared = $crate::ProgressCleared<$state>;
+
+            $crate::__define_progress_state_assets!($state);
+        }
+    };
+}
+
+#[cfg(feature = "assets")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_progress_state_assets {
+    ($state:ty) => {
+        /// Alias for `AssetsLoading` with our states type.
+        pub type AssetsLoading = $crate::AssetsLoading<$state>;
+    };
+}
+
+#[cfg(not(feature = "assets"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_progress_state_assets {
+    ($state:ty) => {};
+}
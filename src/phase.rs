@@ -0,0 +1,203 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use crate::prelude::*;
+
+/// Types that can be used as phases in [`ProgressPhases`].
+///
+/// This is automatically implemented for any suitable type (typically,
+/// your own fieldless enum).
+pub trait Phase: Clone + Eq + Hash + Debug + Send + Sync + 'static {}
+
+impl<T: Clone + Eq + Hash + Debug + Send + Sync + 'static> Phase for T {}
+
+/// Resource for ordered phases of work within a single state.
+///
+/// Sometimes, the work in your loading state happens in steps (for example:
+/// Connect → Download → Decompress → Spawn), and you want to show the
+/// current step to the user, without creating separate Bevy states for
+/// each one. Assign your progress entries to phases, and this resource will
+/// keep track of which phase is current. A phase is complete when all of
+/// its entries are ready. Then, the next phase becomes current.
+///
+/// A phase that has no entries is considered complete. Make sure to assign
+/// all entries before the phase would be reached (for example, using
+/// [`ProgressStore::reserve`] to set up the entries early).
+///
+/// To use this, insert the resource and add the
+/// [`update_progress_phases`] system:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyPhase {
+/// #     Connect,
+/// #     Download,
+/// # }
+/// # let mut app = App::new();
+/// let connect = ProgressEntryId::new();
+/// let download = ProgressEntryId::new();
+///
+/// app.insert_resource(
+///     ProgressPhases::<MyStates, MyPhase>::new([
+///         MyPhase::Connect,
+///         MyPhase::Download,
+///     ])
+///     .with_entry(MyPhase::Connect, connect)
+///     .with_entry(MyPhase::Download, download),
+/// );
+/// app.add_systems(Last,
+///     update_progress_phases::<MyStates, MyPhase>
+///         .in_set(CheckProgressSet)
+/// );
+/// ```
+///
+/// The current phase is reset to the first one whenever the
+/// [`ProgressTracker<S>`] is cleared by [`clear_global_progress`].
+#[derive(Resource)]
+pub struct ProgressPhases<S: ProgressContext, P: Phase> {
+    phases: Vec<(P, Vec<ProgressEntryId>)>,
+    current: usize,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext, P: Phase> ProgressPhases<S, P> {
+    /// Create a new instance, with the given phases (in order).
+    pub fn new(phases: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            phases: phases.into_iter().map(|p| (p, Vec::new())).collect(),
+            current: 0,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Assign an entry to a phase.
+    ///
+    /// (Builder variant)
+    pub fn with_entry(mut self, phase: P, id: ProgressEntryId) -> Self {
+        self.add_entry(phase, id);
+        self
+    }
+
+    /// Assign an entry to a phase.
+    ///
+    /// (Mutable method variant)
+    ///
+    /// Does nothing if the phase does not exist.
+    pub fn add_entry(&mut self, phase: P, id: ProgressEntryId) {
+        if let Some((_, ids)) = self.phases.iter_mut().find(|p| p.0 == phase) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+
+    /// Get the current phase.
+    ///
+    /// Returns `None` if all phases are complete.
+    pub fn current_phase(&self) -> Option<&P> {
+        self.phases.get(self.current).map(|p| &p.0)
+    }
+
+    /// Get the index of the current phase (equal to the number of phases,
+    /// if all phases are complete).
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Get the number of phases.
+    pub fn phase_count(&self) -> usize {
+        self.phases.len()
+    }
+
+    /// Are all phases complete?
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.phases.len()
+    }
+
+    /// Get the (visible) progress of the current phase.
+    ///
+    /// Returns a default (zero) value if all phases are complete.
+    pub fn phase_progress(&self, tracker: &ProgressTracker<S>) -> Progress {
+        self.current_phase()
+            .map(|phase| self.get_phase_progress(phase, tracker))
+            .unwrap_or_default()
+    }
+
+    /// Get the (visible) progress of a specific phase.
+    pub fn get_phase_progress(
+        &self,
+        phase: &P,
+        tracker: &ProgressTracker<S>,
+    ) -> Progress {
+        let Some((_, ids)) = self.phases.iter().find(|p| p.0 == *phase) else {
+            return Progress::default();
+        };
+        tracker.read(|view| {
+            ids.iter().fold(Progress::default(), |sum, id| {
+                sum.saturating_add(view.get_progress(*id))
+            })
+        })
+    }
+
+    /// Check if all the entries of a specific phase are ready.
+    pub fn is_phase_ready(
+        &self,
+        phase: &P,
+        tracker: &ProgressTracker<S>,
+    ) -> bool {
+        let Some((_, ids)) = self.phases.iter().find(|p| p.0 == *phase) else {
+            return false;
+        };
+        tracker.read(|view| ids.iter().all(|id| view.is_id_ready(*id)))
+    }
+}
+
+/// System that advances the current phase of [`ProgressPhases<S, P>`],
+/// when all the entries of the current phase are ready.
+///
+/// You need to add this system to your app yourself, if you use
+/// [`ProgressPhases`].
+pub fn update_progress_phases<S: ProgressContext, P: Phase>(
+    tracker: Res<ProgressTracker<S>>,
+    mut phases: ResMut<ProgressPhases<S, P>>,
+    mut evr_cleared: EventReader<ProgressCleared<S>>,
+) {
+    let mut current = phases.current;
+    if evr_cleared.read().count() > 0 {
+        current = 0;
+    }
+    tracker.read(|view| {
+        while let Some((_, ids)) = phases.phases.get(current) {
+            if !ids.iter().all(|id| view.is_id_ready(*id)) {
+                break;
+            }
+            current += 1;
+        }
+    });
+    if phases.current != current {
+        phases.current = current;
+    }
+}
+
+/// Run condition: is the given phase the current phase of
+/// [`ProgressPhases<S, P>`]?
+///
+/// Returns `false` if the resource does not exist.
+pub fn in_phase<S: ProgressContext, P: Phase>(
+    phase: P,
+) -> impl FnMut(Option<Res<ProgressPhases<S, P>>>) -> bool + Clone {
+    move |phases: Option<Res<ProgressPhases<S, P>>>| {
+        phases.is_some_and(|p| p.current_phase() == Some(&phase))
+    }
+}
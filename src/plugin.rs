@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
@@ -22,35 +25,68 @@ use crate::prelude::*;
 ///         // ...
 ///         .run();
 /// ```
-pub struct ProgressPlugin<S: FreelyMutableState> {
+///
+/// Note: progress is only tracked in the states you configure (using
+/// [`with_state_transition`](Self::with_state_transition) or
+/// [`with_tracked_state`](Self::with_tracked_state)). If you don't
+/// configure any, this plugin will not do anything. With the `debug` cargo
+/// feature enabled, a warning is logged in that case.
+#[derive(Clone)]
+pub struct ProgressPlugin<S: ProgressContext> {
     transitions: StateTransitionConfig<S>,
+    add_transitions: Option<fn(&mut App, InternedScheduleLabel)>,
     check_progress_schedule: InternedScheduleLabel,
+    entity_sum_schedule: InternedScheduleLabel,
+    entity_tracking: bool,
     autoclear_on_enter: bool,
     autoclear_on_exit: bool,
+    entity_entries: bool,
+    display_mode: DisplayMode,
+    snapshot: bool,
+    validation: bool,
+    mirrors: Vec<AddSystemsFn>,
+    #[cfg(feature = "debug")]
+    simulation: Option<ProgressSimulation>,
     #[cfg(feature = "assets")]
     track_assets: bool,
     #[cfg(feature = "assets")]
+    auto_track_assets: Vec<fn(&mut App)>,
+    #[cfg(feature = "assets")]
     autoclear_assets_on_enter: bool,
     #[cfg(feature = "assets")]
     autoclear_assets_on_exit: bool,
 }
 
+type AddSystemsFn = Arc<dyn Fn(&mut App, InternedScheduleLabel) + Send + Sync>;
+
 /// This set represents the "check progress and transition state if ready" step.
 /// It is only useful in the schedule where progress checking occurs (`Last` by
 /// default).
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub struct CheckProgressSet;
 
-impl<S: FreelyMutableState> Default for ProgressPlugin<S> {
+impl<S: ProgressContext> Default for ProgressPlugin<S> {
     fn default() -> Self {
         Self {
             check_progress_schedule: Last.intern(),
+            entity_sum_schedule: PostUpdate.intern(),
+            entity_tracking: true,
             transitions: Default::default(),
+            add_transitions: None,
             autoclear_on_enter: true,
             autoclear_on_exit: false,
+            entity_entries: false,
+            display_mode: DisplayMode::VisibleOnly,
+            snapshot: false,
+            validation: false,
+            mirrors: Vec::new(),
+            #[cfg(feature = "debug")]
+            simulation: None,
             #[cfg(feature = "assets")]
             track_assets: false,
             #[cfg(feature = "assets")]
+            auto_track_assets: Vec::new(),
+            #[cfg(feature = "assets")]
             autoclear_assets_on_enter: false,
             #[cfg(feature = "assets")]
             autoclear_assets_on_exit: true,
@@ -58,7 +94,7 @@ impl<S: FreelyMutableState> Default for ProgressPlugin<S> {
     }
 }
 
-impl<S: FreelyMutableState> ProgressPlugin<S> {
+impl<S: ProgressContext> ProgressPlugin<S> {
     /// Create a new instance of this plugin.
     pub fn new() -> Self {
         Self::default()
@@ -73,8 +109,12 @@ impl<S: FreelyMutableState> ProgressPlugin<S> {
     ///
     /// When all the progress is complete, a state transition to the
     /// `to` state will be queued automatically.
-    pub fn add_state_transition(&mut self, from: S, to: S) {
+    pub fn add_state_transition(&mut self, from: S::State, to: S::State)
+    where
+        S::State: FreelyMutableState,
+    {
         self.transitions.map_from_to.insert(from, to);
+        self.add_transitions = Some(add_transition_systems::<S>);
     }
 
     /// Configure progress tracking in a specific state.
@@ -85,12 +125,274 @@ impl<S: FreelyMutableState> ProgressPlugin<S> {
     /// [`ProgressTracker<S>`] resource will be cleared.
     ///
     /// When all the progress is complete, a state transition to the
-    /// `to` state will be queued automatically.
-    pub fn with_state_transition(mut self, from: S, to: S) -> Self {
+    /// `to` state will be queued automatically. If another transition has
+    /// already been queued by something else (via [`NextState`]), it is
+    /// respected and not overwritten.
+    ///
+    /// If `to` is not itself a progress-tracked state, the final progress
+    /// values are still intact in the [`ProgressTracker<S>`] when its
+    /// `OnEnter` systems run (and afterwards), so you can use them to show
+    /// a summary. This is not the case if you enable clearing on exit (see
+    /// [`auto_clear`](Self::auto_clear)).
+    pub fn with_state_transition(mut self, from: S::State, to: S::State) -> Self
+    where
+        S::State: FreelyMutableState,
+    {
         self.add_state_transition(from, to);
         self
     }
 
+    /// Track progress in a specific state, without any automatic state
+    /// transition.
+    ///
+    /// (Mutable method variant)
+    ///
+    /// Everything works the same as in states configured with
+    /// [`add_state_transition`](Self::add_state_transition) (including
+    /// clearing the [`ProgressTracker<S>`] when the state is entered), except
+    /// that nothing happens when all progress is complete. Check it yourself
+    /// (for example, using [`ProgressStore::is_ready`]).
+    ///
+    /// Unlike state transitions, this works with any kind of states, such
+    /// as computed states and sub-states.
+    pub fn add_tracked_state(&mut self, state: S::State) {
+        self.transitions.tracking_only.insert(state);
+    }
+
+    /// Track progress in a specific state, without any automatic state
+    /// transition.
+    ///
+    /// (Builder variant)
+    ///
+    /// See [`add_tracked_state`](Self::add_tracked_state).
+    pub fn with_tracked_state(mut self, state: S::State) -> Self {
+        self.add_tracked_state(state);
+        self
+    }
+
+    /// Configure whether the visible progress must be displayed as complete
+    /// before transitioning.
+    ///
+    /// If enabled, the state transition will only happen after the visible
+    /// progress has been complete for at least one whole frame, giving your
+    /// UI a chance to show a full progress bar, even if all the work
+    /// (including hidden progress) completes at the same time.
+    ///
+    /// Default: `false`
+    pub fn with_visible_first(mut self, visible_first: bool) -> Self {
+        self.transitions.visible_first = visible_first;
+        self
+    }
+
+    /// Configure whether the visible progress must be displayed as complete
+    /// before transitioning.
+    ///
+    /// See [`with_visible_first`](Self::with_visible_first).
+    pub fn set_visible_first(&mut self, visible_first: bool) {
+        self.transitions.visible_first = visible_first;
+    }
+
+    /// Do not transition out of a progress-tracked state until at least
+    /// `duration` has passed since it was entered, even if all progress is
+    /// complete.
+    ///
+    /// Useful to avoid a loading screen that only flashes up for a couple of
+    /// frames on fast machines. The time is measured in virtual time
+    /// (`Time<Virtual>`), so it does not pass while the app is paused. It
+    /// applies to every state configured with
+    /// [`with_state_transition`](Self::with_state_transition), counting from
+    /// when that state was entered, regardless of when the progress is
+    /// cleared.
+    ///
+    /// Default: no minimum duration
+    pub fn with_minimum_duration(mut self, duration: Duration) -> Self {
+        self.transitions.min_duration = Some(duration);
+        self
+    }
+
+    /// Do not transition out of a progress-tracked state until at least
+    /// `duration` has passed since it was entered.
+    ///
+    /// See [`with_minimum_duration`](Self::with_minimum_duration).
+    pub fn set_minimum_duration(&mut self, duration: Duration) {
+        self.transitions.min_duration = Some(duration);
+    }
+
+    /// Configure whether any progress must have been reported before
+    /// transitioning.
+    ///
+    /// A tracker with nothing in it counts as ready, so if your systems do
+    /// not report anything in the first frame after entering the state, the
+    /// state transition happens right away. If enabled, the transition waits
+    /// until at least one entry exists (or a [`ProgressEntity<S>`] has some
+    /// work). An entry with a `total` of zero still counts, so a state
+    /// whose work legitimately turns out to be empty can still transition,
+    /// as long as something reports it.
+    ///
+    /// Default: `false`
+    pub fn require_registered_progress(mut self, require: bool) -> Self {
+        self.transitions.require_registered = require;
+        self
+    }
+
+    /// Configure whether any progress must have been reported before
+    /// transitioning.
+    ///
+    /// See [`require_registered_progress`](Self::require_registered_progress).
+    pub fn set_require_registered_progress(&mut self, require: bool) {
+        self.transitions.require_registered = require;
+    }
+
+    /// Configure what the progress shown to the user should include.
+    ///
+    /// See [`ProgressStore::get_displayed_progress`].
+    ///
+    /// Default: [`DisplayMode::VisibleOnly`]
+    pub fn with_display_mode(mut self, mode: DisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
+    /// Configure what the progress shown to the user should include.
+    ///
+    /// See [`with_display_mode`](Self::with_display_mode).
+    pub fn set_display_mode(&mut self, mode: DisplayMode) {
+        self.display_mode = mode;
+    }
+
+    /// Limit how fast the displayed progress can increase.
+    ///
+    /// `rate` is the maximum increase of the displayed fraction per second.
+    /// For example, `0.5` means that it takes at least 2 seconds to fill up
+    /// the progress bar from empty, even if all the work completes instantly.
+    /// Seconds are measured in virtual time (`Time<Virtual>`), so the
+    /// displayed progress does not move while the app is paused.
+    ///
+    /// This adds the [`DisplayedProgress<S>`] resource, which you should use
+    /// to display your progress bar. The state transition will wait until
+    /// the displayed progress has reached 100%.
+    ///
+    /// Panics if `rate` is not a finite number greater than zero, because
+    /// the displayed progress would then never reach 100%.
+    ///
+    /// Default: no limit
+    pub fn with_max_fill_rate(mut self, rate: f32) -> Self {
+        self.set_max_fill_rate(rate);
+        self
+    }
+
+    /// Limit how fast the displayed progress can increase.
+    ///
+    /// See [`with_max_fill_rate`](Self::with_max_fill_rate).
+    pub fn set_max_fill_rate(&mut self, rate: f32) {
+        assert!(
+            rate.is_finite() && rate > 0.0,
+            "max fill rate must be finite and greater than zero, got {rate}"
+        );
+        self.transitions.max_fill_rate = Some(rate);
+    }
+
+    /// Require an "exit gate" entry to be ready, before transitioning.
+    ///
+    /// This lets you separate "all the work is done" from "ready to leave
+    /// the loading screen", for example to play a fade-out animation. Once
+    /// all other progress is complete (check using
+    /// [`ProgressStore::is_ready_except`] with the gate ID), start your
+    /// animation. When it is finished, mark the gate entry as ready. Only
+    /// then will the state transition happen.
+    ///
+    /// Until you have created the gate entry in the tracker (by setting any
+    /// progress values for it), the gate is considered closed. Keep in mind
+    /// that the tracker is cleared when entering the state (by default), so
+    /// do it after that.
+    pub fn with_exit_gate(mut self, gate: ProgressEntryId) -> Self {
+        self.transitions.exit_gate = Some(gate);
+        self
+    }
+
+    /// Require an "exit gate" entry to be ready, before transitioning.
+    ///
+    /// See [`with_exit_gate`](Self::with_exit_gate).
+    pub fn set_exit_gate(&mut self, gate: ProgressEntryId) {
+        self.transitions.exit_gate = Some(gate);
+    }
+
+    /// Leave the progress-tracked states immediately if there are errors.
+    ///
+    /// If any entry has an error (see [`ProgressStore::set_error`]), a
+    /// transition to `error_state` is queued right away, regardless of any
+    /// other progress, and a [`ProgressFailure<S>`] resource is inserted
+    /// with the details. This is checked before the normal transition.
+    ///
+    /// Errors are recorded when a system returning progress returns an
+    /// `Err`, when assets fail to load (unless failures are allowed), or
+    /// when you set them manually.
+    pub fn with_error_state(mut self, error_state: S::State) -> Self
+    where
+        S::State: FreelyMutableState,
+    {
+        self.transitions.error_state = Some(error_state);
+        self
+    }
+
+    /// Leave the progress-tracked states immediately if there are errors.
+    ///
+    /// See [`with_error_state`](Self::with_error_state).
+    pub fn set_error_state(&mut self, error_state: S::State)
+    where
+        S::State: FreelyMutableState,
+    {
+        self.transitions.error_state = Some(error_state);
+    }
+
+    /// Customize how the state transition is performed, when all progress
+    /// is complete.
+    ///
+    /// By default, the transition is done by setting [`NextState`]. If you
+    /// manage your states differently (for example, using a state stack),
+    /// provide a function that does it your way. It is given the state to
+    /// transition to. It is called once, when the progress becomes complete.
+    ///
+    /// Unlike the default, a custom transition cannot be cancelled if more
+    /// work appears before it has happened. It is not called again either,
+    /// until the state is entered again.
+    pub fn with_transition_applier(
+        mut self,
+        f: impl Fn(&mut World, &S::State) + Send + Sync + 'static,
+    ) -> Self {
+        self.transitions.applier = Some(std::sync::Arc::new(f));
+        self
+    }
+
+    /// Customize how the state transition is performed, when all progress
+    /// is complete.
+    ///
+    /// See [`with_transition_applier`](Self::with_transition_applier).
+    pub fn set_transition_applier(
+        &mut self,
+        f: impl Fn(&mut World, &S::State) + Send + Sync + 'static,
+    ) {
+        self.transitions.applier = Some(std::sync::Arc::new(f));
+    }
+
+    /// Reserve (visible) expected work items for a specific ID.
+    ///
+    /// The reservation is applied (using [`ProgressStore::reserve`]) every
+    /// time progress is cleared by [`clear_global_progress`], so that the
+    /// total is known from the start, before your systems have had a chance
+    /// to run. Use the same ID to report the actual progress.
+    pub fn with_reservation(mut self, id: ProgressEntryId, total: u32) -> Self {
+        self.transitions.reservations.push((id, total));
+        self
+    }
+
+    /// Reserve (visible) expected work items for a specific ID.
+    ///
+    /// See [`with_reservation`](Self::with_reservation).
+    pub fn add_reservation(&mut self, id: ProgressEntryId, total: u32) {
+        self.transitions.reservations.push((id, total));
+    }
+
     /// Configure in which schedule to check the global progress and queue state
     /// transitions.
     ///
@@ -100,9 +402,31 @@ impl<S: FreelyMutableState> ProgressPlugin<S> {
         self
     }
 
+    /// Configure in which schedule to sum up the values of all
+    /// [`ProgressEntity<S>`] components.
+    ///
+    /// If your systems modify [`ProgressEntity<S>`] components in the same
+    /// schedule, order them before [`EntityProgressSet`], so that the new
+    /// values are tracked in the same frame.
+    ///
+    /// Default: `PostUpdate`
+    pub fn entity_sum_in<L: ScheduleLabel>(mut self, schedule: L) -> Self {
+        self.entity_sum_schedule = schedule.intern();
+        self
+    }
+
     /// Configure whether progress data should be cleared when entering/exiting
     /// a progress-tracked state.
     ///
+    /// Unless `on_exit` is enabled, the final progress values remain readable
+    /// from the [`ProgressTracker<S>`] after the state transition (for
+    /// example, to show a "loading summary" screen). Progress is not updated
+    /// outside of progress-tracked states, so the values will not change
+    /// until they are cleared. The exception are the entries of
+    /// [`ProgressEntity<S>`]s with
+    /// [`with_entity_entries`](Self::with_entity_entries), which are removed
+    /// when their entity is despawned.
+    ///
     /// Default: `on_enter: true, on_exit: false`.
     pub fn auto_clear(mut self, on_enter: bool, on_exit: bool) -> Self {
         self.autoclear_on_enter = on_enter;
@@ -113,12 +437,177 @@ impl<S: FreelyMutableState> ProgressPlugin<S> {
     /// Configure whether progress data should be cleared when entering/exiting
     /// a progress-tracked state.
     ///
+    /// See [`auto_clear`](Self::auto_clear).
+    ///
     /// Default: `on_enter: true, on_exit: false`.
     pub fn set_auto_clear(&mut self, on_enter: bool, on_exit: bool) {
         self.autoclear_on_enter = on_enter;
         self.autoclear_on_exit = on_exit;
     }
 
+    /// Configure whether progress stored in [`ProgressEntity<S>`] components
+    /// should be tracked.
+    ///
+    /// If you do not use [`ProgressEntity<S>`], you can disable this, so that
+    /// the system that sums up the components is not added at all.
+    ///
+    /// Default: `true`
+    pub fn with_entity_tracking(mut self, entity_tracking: bool) -> Self {
+        self.entity_tracking = entity_tracking;
+        self
+    }
+
+    /// Configure whether progress stored in [`ProgressEntity<S>`] components
+    /// should be tracked.
+    ///
+    /// See [`with_entity_tracking`](Self::with_entity_tracking).
+    pub fn set_entity_tracking(&mut self, entity_tracking: bool) {
+        self.entity_tracking = entity_tracking;
+    }
+
+    /// Track every [`ProgressEntity<S>`] as its own entry in the
+    /// [`ProgressTracker<S>`], instead of only tracking the sum.
+    ///
+    /// Each component will get a [`ProgressEntryId`] when it is inserted,
+    /// which you can get via [`ProgressEntity::entry_id`]. The entry is
+    /// removed from the tracker when the component is removed or the entity
+    /// is despawned.
+    ///
+    /// Default: `false`
+    pub fn with_entity_entries(mut self, entity_entries: bool) -> Self {
+        self.entity_entries = entity_entries;
+        self
+    }
+
+    /// Set whether every [`ProgressEntity<S>`] should be tracked as its own
+    /// entry in the [`ProgressTracker<S>`].
+    ///
+    /// See [`with_entity_entries`](Self::with_entity_entries).
+    pub fn set_entity_entries(&mut self, entity_entries: bool) {
+        self.entity_entries = entity_entries;
+    }
+
+    /// Maintain a [`ProgressSnapshot<S>`] resource, with a copy of the
+    /// progress data that can be read without locking.
+    ///
+    /// The snapshot is updated every frame, in [`CheckProgressSet`]. With the
+    /// `reflect` cargo feature, it can also be used with Bevy's
+    /// reflection-based tooling (such as inspectors).
+    ///
+    /// Default: `false`
+    pub fn with_snapshot(mut self, snapshot: bool) -> Self {
+        self.snapshot = snapshot;
+        self
+    }
+
+    /// Configure whether a [`ProgressSnapshot<S>`] resource should be
+    /// maintained.
+    ///
+    /// See [`with_snapshot`](Self::with_snapshot).
+    pub fn set_snapshot(&mut self, snapshot: bool) {
+        self.snapshot = snapshot;
+    }
+
+    /// Configure whether the internal consistency of the
+    /// [`ProgressTracker<S>`] should be checked every frame.
+    ///
+    /// If enabled, a system checks (in [`CheckProgressSet`]) that the global
+    /// progress values match the sum of all the entries, and panics if not.
+    /// This catches accounting bugs in the frame they happen, rather than
+    /// as a wrong progress bar later. It costs a pass over all the entries
+    /// every frame, so it is meant for tests and debug builds.
+    ///
+    /// Default: `false`
+    pub fn with_validation(mut self, validation: bool) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Configure whether the internal consistency of the
+    /// [`ProgressTracker<S>`] should be checked every frame.
+    ///
+    /// See [`with_validation`](Self::with_validation).
+    pub fn set_validation(&mut self, validation: bool) {
+        self.validation = validation;
+    }
+
+    /// Copy progress data into a resource of your own, every frame.
+    ///
+    /// (Mutable method variant)
+    ///
+    /// The `extractor` is called every frame (in [`CheckProgressSet`]), with
+    /// the [`ProgressTracker<S>`] and your resource `R`. Use it to map the
+    /// progress into your own UI data model, so that your UI code does not
+    /// need to know about the tracker (or its generics) at all.
+    ///
+    /// The resource is initialized (using [`FromWorld`]) if it does not
+    /// already exist when the plugin is added.
+    pub fn add_mirror_into<R: Resource + FromWorld>(
+        &mut self,
+        extractor: fn(&ProgressTracker<S>, &mut R),
+    ) {
+        self.mirrors.push(Arc::new(move |app, schedule| {
+            app.init_resource::<R>();
+            app.add_systems(
+                schedule,
+                (move |tracker: Res<ProgressTracker<S>>, mut r: ResMut<R>| {
+                    extractor(&tracker, &mut r);
+                })
+                .in_set(CheckProgressSet),
+            );
+        }));
+    }
+
+    /// Copy progress data into a resource of your own, every frame.
+    ///
+    /// (Builder variant)
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # let mut app = App::new();
+    /// #[derive(Resource, Default)]
+    /// struct LoadingBar {
+    ///     percent: u8,
+    ///     ready: bool,
+    /// }
+    ///
+    /// app.add_plugins(
+    ///     ProgressPlugin::<MyStates>::new()
+    ///         .with_state_transition(MyStates::Loading, MyStates::Done)
+    ///         .mirror_into(|tracker, bar: &mut LoadingBar| {
+    ///             bar.percent = tracker.global_percent();
+    ///             bar.ready = tracker.is_ready();
+    ///         }),
+    /// );
+    /// ```
+    ///
+    /// See [`add_mirror_into`](Self::add_mirror_into).
+    pub fn mirror_into<R: Resource + FromWorld>(
+        mut self,
+        extractor: fn(&ProgressTracker<S>, &mut R),
+    ) -> Self {
+        self.add_mirror_into(extractor);
+        self
+    }
+
+    /// Report fake progress, for developing your loading UI.
+    ///
+    /// See [`ProgressSimulator<S>`]. Note that, since the simulated progress
+    /// is tracked like any other work, the state transition will wait for it
+    /// to complete.
+    #[cfg(feature = "debug")]
+    pub fn with_simulation(mut self, simulation: ProgressSimulation) -> Self {
+        self.simulation = Some(simulation);
+        self
+    }
+
     /// Configure whether assets tracking data should be cleared when
     /// entering/exiting a progress-tracked state.
     ///
@@ -152,25 +641,144 @@ impl<S: FreelyMutableState> ProgressPlugin<S> {
         self.track_assets = true;
         self
     }
+
+    /// Automatically track all assets of type `A`, that the `AssetServer`
+    /// loads while in a progress-tracked state.
+    ///
+    /// (Mutable method variant)
+    ///
+    /// This is useful if other plugins (for example, scene loading) load
+    /// assets that you do not have handles for. Any such asset will be added
+    /// to [`AssetsLoading<S>`] as soon as it is added to its `Assets<A>`
+    /// collection. Assets loaded while not in a progress-tracked state are
+    /// not tracked.
+    ///
+    /// Important: an asset is only added to its `Assets<A>` collection once
+    /// it has finished loading, and Bevy has no event for when a load
+    /// starts. So this does not wait for assets that are still loading: if
+    /// everything else is complete first, the state transition happens
+    /// without them. What it does wait for are the dependencies of the
+    /// assets it sees (if [`AssetsLoading::track_dependencies`] is enabled),
+    /// which makes it useful for assets with many dependencies (like
+    /// scenes). To wait for an asset from the start, add its handle to
+    /// [`AssetsLoading<S>`] yourself, when you start loading it.
+    ///
+    /// You need to call this for every asset type you care about.
+    ///
+    /// Requires the built-in asset tracking to be enabled (see
+    /// [`with_asset_tracking`](Self::with_asset_tracking)).
+    #[cfg(feature = "assets")]
+    pub fn add_auto_track_assets<A: bevy_asset::Asset>(&mut self) {
+        self.auto_track_assets.push(|app| {
+            use crate::assets::*;
+            app.add_systems(
+                PostUpdate,
+                auto_track_assets::<S, A>.before(AssetsTrackProgress),
+            );
+        });
+    }
+
+    /// Automatically track all assets of type `A`, that the `AssetServer`
+    /// loads while in a progress-tracked state.
+    ///
+    /// (Builder variant)
+    ///
+    /// See [`add_auto_track_assets`](Self::add_auto_track_assets).
+    #[cfg(feature = "assets")]
+    pub fn with_auto_track_assets<A: bevy_asset::Asset>(mut self) -> Self {
+        self.add_auto_track_assets::<A>();
+        self
+    }
 }
 
-impl<S: FreelyMutableState> Plugin for ProgressPlugin<S> {
+impl<S: ProgressContext> Plugin for ProgressPlugin<S> {
     fn build(&self, app: &mut App) {
+        #[cfg(feature = "debug")]
+        if self.transitions.tracked_states().next().is_none() {
+            bevy_log::warn!(
+                "ProgressPlugin<{}> added, but no states configured. \
+                 Progress will not be tracked.",
+                std::any::type_name::<S>(),
+            );
+        }
         app.init_resource::<ProgressTracker<S>>();
+        app.world()
+            .resource::<ProgressTracker<S>>()
+            .set_display_mode(self.display_mode);
+        app.add_event::<ProgressCleared<S>>();
+        app.add_event::<ProgressComplete<S>>();
+        app.add_event::<ProgressEntryComplete<S>>();
         app.insert_resource(self.transitions.clone());
+        if let Some(add_transitions) = self.add_transitions {
+            add_transitions(app, self.check_progress_schedule);
+        }
+        app.init_resource::<ProgressChanged<S>>();
         app.add_systems(
             self.check_progress_schedule,
-            transition_if_ready::<S>
+            detect_progress_changes::<S>
                 .run_if(rc_configured_state::<S>)
                 .in_set(CheckProgressSet),
         );
         app.add_systems(
-            PostUpdate,
-            apply_progress_from_entities::<S>
+            self.check_progress_schedule,
+            sample_entry_eta::<S>
                 .run_if(rc_configured_state::<S>)
-                .run_if(any_with_component::<ProgressEntity<S>>),
+                .in_set(CheckProgressSet)
+                .before(TransitionSet),
+        );
+        app.add_systems(
+            self.check_progress_schedule,
+            send_entry_complete_events::<S>
+                .run_if(rc_configured_state::<S>)
+                .in_set(CheckProgressSet)
+                .before(TransitionSet),
         );
         for s in self.transitions.map_from_to.keys() {
+            app.add_systems(OnEnter(s.clone()), reset_transition_state::<S>);
+        }
+        if self.transitions.min_duration.is_some() {
+            app.init_resource::<StateEnteredAt<S>>();
+            for s in self.transitions.tracked_states() {
+                app.add_systems(OnEnter(s.clone()), record_state_entered::<S>);
+            }
+        }
+        if self.validation {
+            app.add_systems(
+                self.check_progress_schedule,
+                validate_progress::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .in_set(CheckProgressSet),
+            );
+        }
+        if self.entity_tracking {
+            app.add_systems(
+                self.entity_sum_schedule,
+                apply_progress_from_entities::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .run_if(any_with_component::<ProgressEntity<S>>)
+                    .in_set(EntityProgressSet),
+            );
+            if self.entity_entries {
+                register_entity_entry_hooks::<S>(app.world_mut());
+            }
+        }
+        if self.transitions.max_fill_rate.is_some() {
+            app.init_resource::<DisplayedProgress<S>>();
+            app.add_systems(
+                self.check_progress_schedule,
+                update_displayed_progress::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .in_set(CheckProgressSet)
+                    .before(TransitionSet),
+            );
+            for s in self.transitions.tracked_states() {
+                app.add_systems(
+                    OnEnter(s.clone()),
+                    reset_displayed_progress::<S>,
+                );
+            }
+        }
+        for s in self.transitions.tracked_states() {
             if self.autoclear_on_enter {
                 app.add_systems(OnEnter(s.clone()), clear_global_progress::<S>);
             }
@@ -186,6 +794,29 @@ impl<S: FreelyMutableState> Plugin for ProgressPlugin<S> {
                     .run_if(rc_configured_state::<S>)
                     .run_if(rc_recv_progress_msgs::<S>),
             );
+            app.add_systems(
+                self.check_progress_schedule,
+                notify_completion::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .run_if(rc_completion_waiters::<S>)
+                    .in_set(CheckProgressSet),
+            );
+            for s in self.transitions.tracked_states() {
+                app.add_systems(OnExit(s.clone()), cancel_background_work::<S>);
+            }
+        }
+        for add_mirror in &self.mirrors {
+            add_mirror(app, self.check_progress_schedule);
+        }
+        if self.snapshot {
+            use crate::snapshot::*;
+            app.init_resource::<ProgressSnapshot<S>>();
+            app.add_systems(
+                self.check_progress_schedule,
+                update_progress_snapshot::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .in_set(CheckProgressSet),
+            );
         }
         #[cfg(feature = "debug")]
         {
@@ -195,21 +826,50 @@ impl<S: FreelyMutableState> Plugin for ProgressPlugin<S> {
                 debug_progress::<S>
                     .run_if(rc_debug_progress::<S>)
                     .in_set(CheckProgressSet)
-                    .before(transition_if_ready::<S>),
+                    .before(TransitionSet),
+            );
+            app.add_systems(
+                self.check_progress_schedule,
+                warn_progress_overflow::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .in_set(CheckProgressSet),
             );
+            app.add_systems(
+                self.check_progress_schedule,
+                log_completion_summary::<S>
+                    .run_if(rc_configured_state::<S>)
+                    .in_set(CheckProgressSet),
+            );
+            if let Some(simulation) = self.simulation {
+                app.insert_resource(ProgressSimulator::<S>::new(simulation));
+                app.add_systems(
+                    Update,
+                    simulate_progress::<S>.run_if(rc_configured_state::<S>),
+                );
+            }
         }
         #[cfg(feature = "assets")]
         if self.track_assets {
             use crate::assets::*;
             app.init_resource::<AssetsLoading<S>>();
+            app.init_resource::<AssetTrackingEnabled<S>>();
+            app.add_event::<AssetsReady<S>>();
             app.add_systems(
                 PostUpdate,
                 assets_progress::<S>
-                    .track_progress::<S>()
                     .in_set(AssetsTrackProgress)
                     .run_if(rc_configured_state::<S>),
             );
-            for s in self.transitions.map_from_to.keys() {
+            for add_auto_track in &self.auto_track_assets {
+                add_auto_track(app);
+            }
+            for s in self.transitions.tracked_states() {
+                app.configure_sets(
+                    OnEnter(s.clone()),
+                    RegisterAssetsSet
+                        .after(clear_global_progress::<S>)
+                        .after(assets_loading_reset::<S>),
+                );
                 if self.autoclear_assets_on_enter {
                     app.add_systems(
                         OnEnter(s.clone()),
@@ -218,9 +878,10 @@ impl<S: FreelyMutableState> Plugin for ProgressPlugin<S> {
                     );
                 }
                 if self.autoclear_assets_on_exit {
+                    // Keep the final values readable in the next state.
                     app.add_systems(
                         OnExit(s.clone()),
-                        assets_loading_reset::<S>
+                        assets_loading_reset_keep_progress::<S>
                             .after(clear_global_progress::<S>),
                     );
                 }
@@ -228,3 +889,69 @@ impl<S: FreelyMutableState> Plugin for ProgressPlugin<S> {
         }
     }
 }
+
+fn add_transition_systems<S: ProgressContext>(
+    app: &mut App,
+    schedule: InternedScheduleLabel,
+) where
+    S::State: FreelyMutableState,
+{
+    app.add_systems(
+        schedule,
+        (transition_on_error::<S>, transition_if_ready::<S>)
+            .chain()
+            .run_if(rc_configured_state::<S>)
+            .in_set(CheckProgressSet)
+            .in_set(TransitionSet),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum MyStates {
+        #[default]
+        Loading,
+        Done,
+    }
+
+    #[derive(Resource, Default)]
+    struct Summary(Option<Progress>);
+
+    #[test]
+    fn final_progress_is_intact_on_enter() {
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(id, 3),
+        );
+        app.app_mut().init_resource::<Summary>().add_systems(
+            OnEnter(MyStates::Done),
+            |tracker: Res<ProgressTracker<MyStates>>,
+             mut summary: ResMut<Summary>| {
+                summary.0 = Some(tracker.get_global_progress());
+            },
+        );
+        app.step().assert_state(MyStates::Loading);
+        app.set_done(id, 3).step_n(2).assert_state(MyStates::Done);
+        let summary = app.app().world().resource::<Summary>();
+        assert_eq!(summary.0, Some(Progress::new(3, 3)));
+        assert_eq!(app.tracker().get_global_progress(), Progress::new(3, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_max_fill_rate_panics() {
+        ProgressPlugin::<MyStates>::new().with_max_fill_rate(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_max_fill_rate_panics() {
+        ProgressPlugin::<MyStates>::new().with_max_fill_rate(f32::NAN);
+    }
+}
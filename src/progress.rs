@@ -9,8 +9,15 @@ use derive_more::derive::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign};
 ///
 /// For your convenience, you can easily convert `bool`s into this type.
 /// You can also convert `Progress` values into floats in the `0.0..=1.0` range.
+///
+/// The arithmetic operators (`+`, `-`, `+=`, `-=`) operate on both fields
+/// using plain `u32` arithmetic, so they panic on overflow/underflow in debug
+/// builds (and wrap around in release builds). If that is a concern, use
+/// [`saturating_add`](Self::saturating_add) and
+/// [`saturating_sub`](Self::saturating_sub) instead.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[derive(Add, AddAssign, Sub, SubAssign)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 pub struct Progress {
     /// The units of work that have been completed.
     pub done: u32,
@@ -27,23 +34,152 @@ impl From<bool> for Progress {
     }
 }
 
+impl From<(u32, u32)> for Progress {
+    fn from((done, total): (u32, u32)) -> Progress {
+        Progress { done, total }
+    }
+}
+
+/// Same as [`Progress::fraction`]: `1.0` (not `NaN`) if `total` is zero.
 impl From<Progress> for f32 {
     fn from(p: Progress) -> f32 {
-        p.done as f32 / p.total as f32
+        p.fraction()
     }
 }
 
+/// Like [`Progress::fraction`], but with more precision: `1.0` (not `NaN`)
+/// if `total` is zero.
 impl From<Progress> for f64 {
     fn from(p: Progress) -> f64 {
-        p.done as f64 / p.total as f64
+        if p.total == 0 {
+            1.0
+        } else {
+            p.done as f64 / p.total as f64
+        }
     }
 }
 
 impl Progress {
+    /// The `total` used by [`from_fraction`](Self::from_fraction).
+    ///
+    /// High enough that a progress bar filled from it looks perfectly smooth.
+    pub const FRACTION_RESOLUTION: u32 = 1_000_000;
+
+    /// Create a new progress value.
+    pub const fn new(done: u32, total: u32) -> Progress {
+        Progress { done, total }
+    }
+
+    /// Create a progress value where all the work is complete
+    /// (`done` equals `total`).
+    pub const fn complete(total: u32) -> Progress {
+        Progress { done: total, total }
+    }
+
+    /// Create a progress value where none of the work has been done yet
+    /// (`done` is zero).
+    pub const fn none(total: u32) -> Progress {
+        Progress { done: 0, total }
+    }
+
+    /// Create a progress value from a completed fraction (`0.0..=1.0`).
+    ///
+    /// Useful for work that is inherently fractional (like a download
+    /// reporting a percentage). The `total` is always
+    /// [`FRACTION_RESOLUTION`](Self::FRACTION_RESOLUTION). Values outside of
+    /// the range are clamped, and NaN is treated as `0.0`.
+    pub fn from_fraction(fraction: f32) -> Progress {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let done = (fraction as f64 * Self::FRACTION_RESOLUTION as f64) as u32;
+        Progress {
+            done,
+            total: Self::FRACTION_RESOLUTION,
+        }
+    }
+
     /// Returns true if `done` has reached `total`
     pub fn is_ready(self) -> bool {
         self.done >= self.total
     }
+
+    /// Add two progress values, saturating at `u32::MAX` instead of
+    /// overflowing.
+    pub fn saturating_add(self, rhs: Progress) -> Progress {
+        Progress {
+            done: self.done.saturating_add(rhs.done),
+            total: self.total.saturating_add(rhs.total),
+        }
+    }
+
+    /// Subtract two progress values, saturating at zero instead of
+    /// underflowing.
+    pub fn saturating_sub(self, rhs: Progress) -> Progress {
+        Progress {
+            done: self.done.saturating_sub(rhs.done),
+            total: self.total.saturating_sub(rhs.total),
+        }
+    }
+
+    /// Compare two progress values by their completion ratio
+    /// (`done / total`), not by the raw `done` counts.
+    ///
+    /// Progress with `total` of zero is treated as complete, consistent with
+    /// [`is_ready`](Self::is_ready). Values where `done` exceeds `total` are
+    /// treated as exactly complete, so all complete values compare as equal.
+    ///
+    /// This is not an `Ord` impl, because it is not consistent with `Eq`
+    /// (for example, `1/2` and `2/4` compare as equal). Use it for sorting:
+    ///
+    /// ```rust
+    /// # use iyes_progress::prelude::*;
+    /// # let mut entries = vec![Progress::new(1, 2), Progress::new(0, 3)];
+    /// // least complete first
+    /// entries.sort_by(|a, b| a.cmp_by_fraction(b));
+    /// ```
+    pub fn cmp_by_fraction(&self, other: &Progress) -> std::cmp::Ordering {
+        fn ratio(p: &Progress) -> (u64, u64) {
+            if p.total == 0 {
+                (1, 1)
+            } else {
+                (p.done.min(p.total) as u64, p.total as u64)
+            }
+        }
+        let (a_done, a_total) = ratio(self);
+        let (b_done, b_total) = ratio(other);
+        // a_done/a_total vs b_done/b_total, without floats
+        (a_done * b_total).cmp(&(b_done * a_total))
+    }
+
+    /// Returns the completed percentage (`0..=100`), rounded, for display.
+    ///
+    /// This only returns `100` if the progress [`is_ready`](Self::is_ready):
+    /// while any work remains, the result is at most `99`, even if it would
+    /// round up to `100`. If `total` is zero, this returns `100`.
+    pub fn percent(self) -> u8 {
+        if self.is_ready() {
+            return 100;
+        }
+        let done = self.done as u64;
+        let total = self.total as u64;
+        ((done * 100 + total / 2) / total).min(99) as u8
+    }
+
+    /// Returns the completed fraction (`done / total`).
+    ///
+    /// If `total` is zero, this returns `1.0`, consistent with
+    /// [`is_ready`](Self::is_ready). The result is not clamped: if `done`
+    /// is greater than `total`, it is greater than `1.0`.
+    pub fn fraction(self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done as f32 / self.total as f32
+        }
+    }
 }
 
 /// Represents progress that is intended to be "hidden" from the user.
@@ -51,11 +187,47 @@ impl Progress {
 /// Such progress must be completed in order to advance state (or generally
 /// consider everything to be ready), but is not intended to be shown in UI
 /// progress bars or other user-facing progress indicators.
+///
+/// Like with [`Progress`], the arithmetic operators can overflow/underflow.
+/// Use [`saturating_add`](Self::saturating_add) and
+/// [`saturating_sub`](Self::saturating_sub) if that is a concern.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[derive(Add, AddAssign, Sub, SubAssign)]
 #[derive(Deref, DerefMut)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 pub struct HiddenProgress(pub Progress);
 
+impl HiddenProgress {
+    /// Create a new progress value.
+    pub const fn new(done: u32, total: u32) -> HiddenProgress {
+        HiddenProgress(Progress::new(done, total))
+    }
+
+    /// Create a progress value where all the work is complete
+    /// (`done` equals `total`).
+    pub const fn complete(total: u32) -> HiddenProgress {
+        HiddenProgress(Progress::complete(total))
+    }
+
+    /// Create a progress value where none of the work has been done yet
+    /// (`done` is zero).
+    pub const fn none(total: u32) -> HiddenProgress {
+        HiddenProgress(Progress::none(total))
+    }
+
+    /// Add two progress values, saturating at `u32::MAX` instead of
+    /// overflowing.
+    pub fn saturating_add(self, rhs: HiddenProgress) -> HiddenProgress {
+        HiddenProgress(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtract two progress values, saturating at zero instead of
+    /// underflowing.
+    pub fn saturating_sub(self, rhs: HiddenProgress) -> HiddenProgress {
+        HiddenProgress(self.0.saturating_sub(rhs.0))
+    }
+}
+
 impl From<Progress> for HiddenProgress {
     fn from(value: Progress) -> Self {
         Self(value)
@@ -74,6 +246,12 @@ impl From<bool> for HiddenProgress {
     }
 }
 
+impl From<(u32, u32)> for HiddenProgress {
+    fn from(value: (u32, u32)) -> HiddenProgress {
+        Progress::from(value).into()
+    }
+}
+
 impl From<HiddenProgress> for f32 {
     fn from(p: HiddenProgress) -> f32 {
         f32::from(p.0)
@@ -85,3 +263,34 @@ impl From<HiddenProgress> for f64 {
         f64::from(p.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_total_is_complete() {
+        assert_eq!(f32::from(Progress::new(0, 0)), 1.0);
+        assert_eq!(f64::from(Progress::new(0, 0)), 1.0);
+        assert_eq!(f32::from(Progress::new(3, 0)), 1.0);
+        assert_eq!(f32::from(HiddenProgress::new(0, 0)), 1.0);
+        assert_eq!(f64::from(HiddenProgress::new(0, 0)), 1.0);
+    }
+
+    #[test]
+    fn normal_values() {
+        assert_eq!(f32::from(Progress::new(0, 1)), 0.0);
+        assert_eq!(f32::from(Progress::new(1, 1)), 1.0);
+        assert_eq!(f32::from(Progress::new(3, 5)), 0.6);
+        assert_eq!(f64::from(Progress::new(0, 1)), 0.0);
+        assert_eq!(f64::from(Progress::new(1, 1)), 1.0);
+        assert_eq!(f64::from(Progress::new(3, 5)), 0.6);
+        assert_eq!(f32::from(HiddenProgress::new(3, 5)), 0.6);
+    }
+
+    #[test]
+    fn done_over_total_is_not_clamped() {
+        assert_eq!(f32::from(Progress::new(5, 4)), 1.25);
+        assert_eq!(f64::from(Progress::new(5, 4)), 1.25);
+    }
+}
@@ -0,0 +1,43 @@
+use bevy_ecs::prelude::*;
+use bevy_scene::{InstanceId, SceneSpawner};
+
+use crate::prelude::*;
+
+/// Make a system that waits for a scene instance to be spawned.
+///
+/// Spawning a scene (for example, using
+/// [`SceneSpawner::spawn_dynamic`]) can take several frames, because the
+/// scene asset has to finish loading first. The system returns
+/// [`Progress`], so add it with
+/// [`track_progress`](ProgressReturningSystem::track_progress):
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # let mut app = App::new();
+/// # let mut scene_spawner = bevy::scene::SceneSpawner::default();
+/// # let level_scene: Handle<DynamicScene> = Handle::default();
+/// let instance = scene_spawner.spawn_dynamic(level_scene.clone());
+/// app.add_systems(
+///     Update,
+///     wait_for_scene_instance(instance)
+///         .track_progress::<MyStates>()
+///         .run_if(in_state(MyStates::Loading)),
+/// );
+/// ```
+///
+/// The instance counts as ready once all of its entities have been spawned
+/// (see [`SceneSpawner::instance_is_ready`]).
+pub fn wait_for_scene_instance(
+    instance: InstanceId,
+) -> impl System<In = (), Out = Progress> {
+    IntoSystem::into_system(move |spawner: Res<SceneSpawner>| -> Progress {
+        spawner.instance_is_ready(instance).into()
+    })
+}
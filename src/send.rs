@@ -1,5 +1,7 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 use bevy_ecs::prelude::*;
-use bevy_state::state::FreelyMutableState;
 
 use crate::prelude::*;
 
@@ -10,7 +12,7 @@ pub(crate) type Receiver =
 
 /// A "handle" to send progress updates from a background thread or async task.
 ///
-/// To create an instance of this struct, call [`ProgressTracker::new_async_entry`].
+/// To create an instance of this struct, call [`ProgressStore::new_async_entry`].
 ///
 /// Each instance of this struct represents a [`ProgressEntryId`] in the
 /// [`ProgressTracker<S>`] resource. If you clone it, you create another
@@ -20,10 +22,19 @@ pub(crate) type Receiver =
 /// entry, a message will be sent via an internal channel. A system running
 /// in `PreUpdate` will read these messages and actually update the entry
 /// in the [`ProgressTracker`].
+///
+/// This works the same whether your work runs on an OS thread or as an
+/// async task (for example, on Bevy's `AsyncComputeTaskPool`): move the
+/// sender into it.
+///
+/// If the work is no longer needed (for example, because the user left the
+/// loading state early), the sender is cancelled. Long-running work should
+/// check [`is_cancelled`](Self::is_cancelled) periodically and stop early.
 #[derive(Clone)]
 pub struct ProgressSender {
     pub(crate) id: ProgressEntryId,
     pub(crate) sender: Sender,
+    pub(crate) cancelled: Arc<AtomicBool>,
 }
 
 impl ProgressSender {
@@ -32,6 +43,16 @@ impl ProgressSender {
         self.id
     }
 
+    /// Has the work been cancelled?
+    ///
+    /// This becomes `true` when the progress-tracked state is exited, when
+    /// the [`ProgressTracker`] is cleared, or when
+    /// [`ProgressStore::cancel_all`] is called. Your background work should
+    /// stop when it sees this. Any progress it sends afterwards is ignored.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
     fn msg(&self, msg: ProgressMessage) {
         self.sender.try_send((self.id, msg)).ok();
     }
@@ -41,6 +62,14 @@ impl ProgressSender {
         self.msg(ProgressMessage::SetProgress(done, total));
     }
 
+    /// Set the visible progress from a completed fraction (`0.0..=1.0`).
+    ///
+    /// See [`Progress::from_fraction`].
+    pub fn set_fraction(&self, fraction: f32) {
+        let p = Progress::from_fraction(fraction);
+        self.msg(ProgressMessage::SetProgress(p.done, p.total));
+    }
+
     /// Set the hidden progress.
     pub fn set_hidden_progress(&self, done: u32, total: u32) {
         self.msg(ProgressMessage::SetHiddenProgress(done, total));
@@ -95,6 +124,18 @@ impl ProgressSender {
     pub fn add_hidden_done(&self, done: u32) {
         self.msg(ProgressMessage::AddHiddenDone(done));
     }
+
+    /// Set the status message.
+    ///
+    /// See [`ProgressStore::set_status`].
+    pub fn set_status(&self, status: impl Into<String>) {
+        self.msg(ProgressMessage::SetStatus(Some(status.into())));
+    }
+
+    /// Remove the status message.
+    pub fn clear_status(&self) {
+        self.msg(ProgressMessage::SetStatus(None));
+    }
 }
 
 pub(crate) enum ProgressMessage {
@@ -110,18 +151,47 @@ pub(crate) enum ProgressMessage {
     AddDone(u32),
     AddHiddenTotal(u32),
     AddHiddenDone(u32),
+    SetStatus(Option<String>),
+}
+
+pub(crate) fn cancel_background_work<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+) {
+    tracker.cancel_all();
 }
 
-pub(crate) fn rc_recv_progress_msgs<S: FreelyMutableState>(
+pub(crate) fn rc_completion_waiters<S: ProgressContext>(
     tracker: Res<ProgressTracker<S>>,
 ) -> bool {
-    tracker.chan.is_some()
+    tracker.has_completion_waiters()
 }
 
-pub(crate) fn recv_progress_msgs<S: FreelyMutableState>(
+pub(crate) fn notify_completion<S: ProgressContext>(
     tracker: Res<ProgressTracker<S>>,
 ) {
-    let Some((_, rx)) = &tracker.chan else {
+    if tracker.is_ready() {
+        tracker.notify_completion_waiters();
+    }
+}
+
+/// The channel only exists once a [`ProgressSender`] has been handed out,
+/// and even then, there is only something to do if messages are waiting.
+pub(crate) fn rc_recv_progress_msgs<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+) -> bool {
+    tracker
+        .chan
+        .lock()
+        .as_ref()
+        .is_some_and(|(_, rx)| !rx.is_empty())
+}
+
+pub(crate) fn recv_progress_msgs<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+) {
+    // Do not hold the lock while applying the messages.
+    let Some(rx) = tracker.chan.lock().as_ref().map(|(_, rx)| rx.clone())
+    else {
         return;
     };
     rx.try_iter().for_each(|msg| match msg.1 {
@@ -161,5 +231,11 @@ pub(crate) fn recv_progress_msgs<S: FreelyMutableState>(
         ProgressMessage::AddHiddenDone(done) => {
             tracker.add_hidden_done(msg.0, done);
         }
+        ProgressMessage::SetStatus(Some(status)) => {
+            tracker.set_status(msg.0, status);
+        }
+        ProgressMessage::SetStatus(None) => {
+            tracker.clear_status(msg.0);
+        }
     });
 }
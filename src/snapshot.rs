@@ -0,0 +1,218 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use crate::prelude::*;
+
+/// A copy of the values stored in the [`ProgressTracker<S>`], taken once
+/// per frame, that can be read without locking.
+///
+/// Every read from the [`ProgressTracker<S>`] locks its mutex, which
+/// contends with any systems that are reporting progress at the same time.
+/// If you have systems (like complex UIs) that read a lot of values, they
+/// can use this resource instead. It is a plain resource, so reading it is
+/// free, and any number of systems can read it in parallel.
+///
+/// The snapshot is updated every frame (in [`CheckProgressSet`]), while in
+/// a progress-tracked state. Systems that run before that see the values as
+/// of the previous frame.
+///
+/// With the `reflect` cargo feature, this resource implements `Reflect`, so
+/// the progress data can be viewed using Bevy's reflection-based tooling
+/// (such as inspectors). The [`ProgressTracker<S>`] itself cannot be
+/// reflected, because its data is behind a mutex. The type is generic, so
+/// you need to register it yourself:
+///
+/// ```rust,ignore
+/// app.register_type::<ProgressSnapshot<MyStates>>();
+/// ```
+///
+/// This resource is only available if the [`ProgressPlugin`] was configured
+/// [`with_snapshot`](ProgressPlugin::with_snapshot).
+#[derive(Resource)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct ProgressSnapshot<S: ProgressContext> {
+    progress: Progress,
+    hidden_progress: HiddenProgress,
+    ready: bool,
+    // Sorted by ID
+    entries: Vec<ProgressSnapshotEntry>,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    _pd: PhantomData<S>,
+}
+
+/// The values of one entry in a [`ProgressSnapshot`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+struct ProgressSnapshotEntry {
+    /// The numeric value of the [`ProgressEntryId`].
+    id: usize,
+    progress: Progress,
+    hidden_progress: HiddenProgress,
+}
+
+impl<S: ProgressContext> Default for ProgressSnapshot<S> {
+    fn default() -> Self {
+        Self {
+            progress: Default::default(),
+            hidden_progress: Default::default(),
+            ready: false,
+            entries: Vec::new(),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<S: ProgressContext> ProgressSnapshot<S> {
+    /// Get the overall visible progress.
+    pub fn get_global_progress(&self) -> Progress {
+        self.progress
+    }
+
+    /// Get the overall hidden progress.
+    pub fn get_global_hidden_progress(&self) -> HiddenProgress {
+        self.hidden_progress
+    }
+
+    /// Get the overall visible+hidden progress.
+    pub fn get_global_combined_progress(&self) -> Progress {
+        self.progress.saturating_add(self.hidden_progress.0)
+    }
+
+    /// Was everything ready?
+    ///
+    /// See [`ProgressStore::is_ready`].
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Get the number of entries.
+    pub fn entry_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get_entry(&self, id: ProgressEntryId) -> Option<&ProgressSnapshotEntry> {
+        self.entries
+            .binary_search_by_key(&id.0, |e| e.id)
+            .ok()
+            .map(|i| &self.entries[i])
+    }
+
+    /// Check if there was an entry for a specific ID.
+    pub fn contains_id(&self, id: ProgressEntryId) -> bool {
+        self.get_entry(id).is_some()
+    }
+
+    /// Get the visible progress of a specific ID.
+    pub fn get_progress(&self, id: ProgressEntryId) -> Progress {
+        self.get_entry(id).map(|e| e.progress).unwrap_or_default()
+    }
+
+    /// Get the hidden progress of a specific ID.
+    pub fn get_hidden_progress(&self, id: ProgressEntryId) -> HiddenProgress {
+        self.get_entry(id)
+            .map(|e| e.hidden_progress)
+            .unwrap_or_default()
+    }
+
+    /// Get the visible+hidden progress of a specific ID.
+    pub fn get_combined_progress(&self, id: ProgressEntryId) -> Progress {
+        self.get_entry(id)
+            .map(|e| e.progress.saturating_add(e.hidden_progress.0))
+            .unwrap_or_default()
+    }
+
+    /// Iterate over all the entries, sorted by [`ProgressEntryId`].
+    pub fn entries(
+        &self,
+    ) -> impl Iterator<Item = (ProgressEntryId, Progress, HiddenProgress)> + '_
+    {
+        self.entries
+            .iter()
+            .map(|e| (ProgressEntryId(e.id), e.progress, e.hidden_progress))
+    }
+}
+
+pub(crate) fn update_progress_snapshot<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+    mut snapshot: ResMut<ProgressSnapshot<S>>,
+) {
+    let snapshot = &mut *snapshot;
+    tracker.read(|view| {
+        snapshot.progress = view.get_global_progress();
+        snapshot.hidden_progress = view.get_global_hidden_progress();
+        snapshot.ready = view.is_ready();
+        snapshot.entries.clear();
+        snapshot.entries.extend(view.entries().map(
+            |(id, progress, hidden_progress)| ProgressSnapshotEntry {
+                id: id.0,
+                progress,
+                hidden_progress,
+            },
+        ));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_state::prelude::*;
+
+    use super::*;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum MyStates {
+        #[default]
+        Loading,
+    }
+
+    #[test]
+    fn snapshot_matches_tracker() {
+        let mut world = World::new();
+        world.init_resource::<ProgressTracker<MyStates>>();
+        world.init_resource::<ProgressSnapshot<MyStates>>();
+        let tracker = world.resource::<ProgressTracker<MyStates>>();
+        let ids: Vec<_> = (0..3).map(|_| tracker.new_id()).collect();
+        tracker.set_progress(ids[2], 1, 2);
+        tracker.set_progress(ids[0], 3, 3);
+        tracker.set_hidden_progress(ids[1], 0, 4);
+        let missing = tracker.new_id();
+
+        world
+            .run_system_once(update_progress_snapshot::<MyStates>)
+            .unwrap();
+
+        let tracker = world.resource::<ProgressTracker<MyStates>>();
+        let snapshot = world.resource::<ProgressSnapshot<MyStates>>();
+        assert_eq!(
+            snapshot.get_global_progress(),
+            tracker.get_global_progress()
+        );
+        assert_eq!(
+            snapshot.get_global_combined_progress(),
+            tracker.get_global_combined_progress()
+        );
+        assert_eq!(snapshot.is_ready(), tracker.is_ready());
+        assert_eq!(snapshot.entry_count(), 3);
+        assert!(snapshot.entries().map(|e| e.0).eq(tracker
+            .read(|view| view.entries().map(|e| e.0).collect::<Vec<_>>())));
+        for id in ids {
+            assert!(snapshot.contains_id(id));
+            assert_eq!(snapshot.get_progress(id), tracker.get_progress(id));
+            assert_eq!(
+                snapshot.get_hidden_progress(id),
+                tracker.get_hidden_progress(id)
+            );
+            assert_eq!(
+                snapshot.get_combined_progress(id),
+                tracker.get_combined_progress(id)
+            );
+        }
+        assert!(!snapshot.contains_id(missing));
+        assert_eq!(snapshot.get_progress(missing), Progress::default());
+    }
+}
@@ -1,25 +1,174 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
 use bevy_ecs::prelude::*;
 #[cfg(feature = "debug")]
 use bevy_log::prelude::*;
-use bevy_state::state::{FreelyMutableState, NextState, State};
-use bevy_utils::HashMap;
+use bevy_state::state::{
+    FreelyMutableState, NextState, State, StateTransition,
+};
+use bevy_time::{Time, Virtual};
+use bevy_utils::{HashMap, HashSet};
 
 use crate::prelude::*;
 
+/// Resource with the state transitions configured in the [`ProgressPlugin`].
+///
+/// You can use this to find out which states are tracked and where they
+/// transition to (for example, for an editor or debug view). It can only be
+/// configured via the [`ProgressPlugin`].
 #[derive(Resource, Clone)]
-pub(crate) struct StateTransitionConfig<S: FreelyMutableState> {
-    pub(crate) map_from_to: HashMap<S, S>,
+pub struct StateTransitionConfig<S: ProgressContext> {
+    pub(crate) map_from_to: HashMap<S::State, S::State>,
+    pub(crate) tracking_only: HashSet<S::State>,
+    pub(crate) visible_first: bool,
+    pub(crate) require_registered: bool,
+    pub(crate) min_duration: Option<Duration>,
+    pub(crate) max_fill_rate: Option<f32>,
+    pub(crate) exit_gate: Option<ProgressEntryId>,
+    pub(crate) reservations: Vec<(ProgressEntryId, u32)>,
+    pub(crate) error_state: Option<S::State>,
+    pub(crate) applier: Option<TransitionApplier<S::State>>,
+    /// Whether the visible progress was complete at the last check, for
+    /// `visible_first`.
+    pub(crate) visible_shown: bool,
+    /// Whether we have queued the transition since entering the state.
+    pub(crate) queued: bool,
+}
+
+/// Internal set for the systems that perform the automatic state transition.
+///
+/// Other systems are ordered before it, without having to name the systems
+/// (which are only available for freely mutable states).
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct TransitionSet;
+
+/// Internal resource with the (virtual) time when the current
+/// progress-tracked state was entered, for
+/// [`ProgressPlugin::with_minimum_duration`].
+#[derive(Resource)]
+pub(crate) struct StateEnteredAt<S: ProgressContext> {
+    elapsed: Duration,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for StateEnteredAt<S> {
+    fn default() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            _pd: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn record_state_entered<S: ProgressContext>(
+    mut entered: ResMut<StateEnteredAt<S>>,
+    time: Option<Res<Time<Virtual>>>,
+) {
+    entered.elapsed = time.map(|t| t.elapsed()).unwrap_or_default();
 }
 
-impl<S: FreelyMutableState> Default for StateTransitionConfig<S> {
+/// Reset the state of the automatic transition, when (re-)entering a state.
+pub(crate) fn reset_transition_state<S: ProgressContext>(
+    mut config: ResMut<StateTransitionConfig<S>>,
+) {
+    let config = config.bypass_change_detection();
+    config.visible_shown = false;
+    config.queued = false;
+}
+
+pub(crate) type TransitionApplier<S> =
+    Arc<dyn Fn(&mut World, &S) + Send + Sync>;
+
+impl<S: ProgressContext> Default for StateTransitionConfig<S> {
     fn default() -> Self {
         Self {
             map_from_to: Default::default(),
+            tracking_only: Default::default(),
+            visible_first: false,
+            require_registered: false,
+            min_duration: None,
+            max_fill_rate: None,
+            exit_gate: None,
+            reservations: Vec::new(),
+            error_state: None,
+            applier: None,
+            visible_shown: false,
+            queued: false,
         }
     }
 }
 
-/// System that calls [`ProgressTracker::clear`].
+/// Event sent by [`clear_global_progress`] every time the
+/// [`ProgressTracker<S>`] is cleared.
+///
+/// Use it (via `EventReader`) to re-register your work after a clear,
+/// without having to know in which schedule the clearing happens.
+#[derive(Event, Debug)]
+pub struct ProgressCleared<S: ProgressContext> {
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for ProgressCleared<S> {
+    fn default() -> Self {
+        Self { _pd: PhantomData }
+    }
+}
+
+/// Event sent when all progress is complete, in the same frame as the
+/// automatic state transition is queued.
+///
+/// The transition is only applied at the start of the next frame, so you
+/// can use this to do something right before leaving the state (like
+/// stopping your loading music):
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # fn stop_loading_music() {}
+/// # let mut app = App::new();
+/// app.add_systems(
+///     Update,
+///     stop_loading_music
+///         .run_if(on_event::<ProgressComplete<MyStates>>),
+/// );
+/// ```
+///
+/// It is sent once per completion, not every frame while ready. If more
+/// work appears and the transition is cancelled, it is sent again when
+/// that work is complete too. Only sent for states configured with
+/// [`ProgressPlugin::with_state_transition`].
+#[derive(Event, Debug, Clone)]
+pub struct ProgressComplete<S: ProgressContext> {
+    /// The state whose progress is complete.
+    pub from: S::State,
+    /// The state we are transitioning to.
+    pub to: S::State,
+}
+
+/// Resource with the error that caused a transition to the error state.
+///
+/// Inserted when leaving a progress-tracked state because of an error, if
+/// the [`ProgressPlugin`] was configured
+/// [`with_error_state`](ProgressPlugin::with_error_state).
+#[derive(Resource, Debug, Clone)]
+pub struct ProgressFailure<S: ProgressContext> {
+    /// The state we were in when the error happened.
+    pub state: S::State,
+    /// The entry that had the error.
+    pub id: ProgressEntryId,
+    /// The error message.
+    pub message: String,
+}
+
+/// System that calls [`ProgressStore::clear`].
 ///
 /// This will be automatically added to the `OnEnter`/`OnExit`
 /// schedules of progress-tracked states, if so configured
@@ -27,32 +176,461 @@ impl<S: FreelyMutableState> Default for StateTransitionConfig<S> {
 ///
 /// This `fn` is `pub` so you can order your systems around it.
 /// Or add other "clearing points" to your app.
-pub fn clear_global_progress<S: FreelyMutableState>(
-    mut gpt: ResMut<ProgressTracker<S>>,
+///
+/// Also sends a [`ProgressCleared<S>`] event, and re-applies any
+/// reservations configured using [`ProgressPlugin::with_reservation`].
+///
+/// Does nothing (not even sending the event) if
+/// [`ProgressStore::skip_next_clear`] was called since the last time.
+pub fn clear_global_progress<S: ProgressContext>(
+    gpt: Res<ProgressTracker<S>>,
+    config: Option<Res<StateTransitionConfig<S>>>,
+    mut evw: EventWriter<ProgressCleared<S>>,
 ) {
+    if gpt.take_skip_next_clear() {
+        #[cfg(feature = "debug")]
+        debug!("Not clearing progress data (skip requested).");
+        return;
+    }
     gpt.clear();
+    if let Some(config) = config {
+        for (id, total) in config.reservations.iter() {
+            gpt.reserve(*id, *total);
+        }
+    }
+    evw.send_default();
     #[cfg(feature = "debug")]
     debug!("Clearing progress data.");
 }
 
-pub(crate) fn rc_configured_state<S: FreelyMutableState>(
+impl<S: ProgressContext> StateTransitionConfig<S> {
+    /// Iterate over all the configured `(from, to)` transitions.
+    pub fn transitions(&self) -> impl Iterator<Item = (&S::State, &S::State)> {
+        self.map_from_to.iter()
+    }
+
+    /// Get the state that `from` will transition to, when its progress is
+    /// complete. `None` if progress is not tracked in `from`.
+    pub fn get_next_state(&self, from: &S::State) -> Option<&S::State> {
+        self.map_from_to.get(from)
+    }
+
+    /// Check if progress is tracked in a given state.
+    ///
+    /// This includes states configured with
+    /// [`ProgressPlugin::with_tracked_state`], which have no transition.
+    pub fn is_tracked(&self, state: &S::State) -> bool {
+        self.map_from_to.contains_key(state)
+            || self.tracking_only.contains(state)
+    }
+
+    /// Iterate over all the states where progress is tracked.
+    pub(crate) fn tracked_states(&self) -> impl Iterator<Item = &S::State> {
+        self.map_from_to.keys().chain(self.tracking_only.iter())
+    }
+
+    /// Get the state to go to if there is an error, if one was configured.
+    ///
+    /// See [`ProgressPlugin::with_error_state`].
+    pub fn error_state(&self) -> Option<&S::State> {
+        self.error_state.as_ref()
+    }
+
+    /// Get the exit gate entry, if one was configured.
+    ///
+    /// See [`ProgressPlugin::with_exit_gate`].
+    pub fn exit_gate(&self) -> Option<ProgressEntryId> {
+        self.exit_gate
+    }
+}
+
+/// Check if all progress is complete and, if so, transition to the next
+/// state right away.
+///
+/// Normally, progress is checked once per frame (in `Last`, by default),
+/// and the state transition happens at the start of the following frame.
+/// Call this (for example, from an exclusive system or a test) to do both
+/// immediately, using the same logic as the automatic check (including
+/// the [error state](ProgressPlugin::with_error_state) and any
+/// [custom transition](ProgressPlugin::with_transition_applier)). Any
+/// `OnExit` and `OnEnter` systems run before this returns.
+///
+/// Returns `true` if a state transition happened, or if a custom transition
+/// applier was called.
+pub fn check_and_transition_now<S: ProgressContext>(world: &mut World) -> bool
+where
+    S::State: FreelyMutableState,
+{
+    let (Some(config), Some(state)) = (
+        world.get_resource::<StateTransitionConfig<S>>(),
+        world.get_resource::<State<S::State>>(),
+    ) else {
+        return false;
+    };
+    if !config.is_tracked(state.get()) {
+        return false;
+    }
+    let old = state.get().clone();
+    let was_queued = config.queued;
+    // The same systems as the automatic check. Their state is kept in the
+    // config, so it is shared with the automatic check.
+    world.run_system_cached(transition_on_error::<S>).ok();
+    world.run_system_cached(transition_if_ready::<S>).ok();
+    let config = world.resource::<StateTransitionConfig<S>>();
+    let applied = config.applier.is_some() && !was_queued && config.queued;
+    if matches!(
+        world.get_resource::<NextState<S::State>>(),
+        Some(NextState::Pending(_))
+    ) {
+        world.run_schedule(StateTransition);
+    }
+    applied || world.resource::<State<S::State>>().get() != &old
+}
+
+pub(crate) fn rc_configured_state<S: ProgressContext>(
     config: Res<StateTransitionConfig<S>>,
-    state: Res<State<S>>,
+    state: Res<State<S::State>>,
 ) -> bool {
-    config.map_from_to.contains_key(state.get())
+    config.is_tracked(state.get())
 }
 
-pub(crate) fn transition_if_ready<S: FreelyMutableState>(
+pub(crate) fn transition_on_error<S: ProgressContext>(
     gpt: Res<ProgressTracker<S>>,
     config: Res<StateTransitionConfig<S>>,
-    state: Res<State<S>>,
-    mut next_state: ResMut<NextState<S>>,
-) {
-    if let Some(to) = config.map_from_to.get(state.get()) {
-        if gpt.is_ready() {
+    state: Res<State<S::State>>,
+    mut next_state: ResMut<NextState<S::State>>,
+    mut commands: Commands,
+) where
+    S::State: FreelyMutableState,
+{
+    let Some(error_state) = &config.error_state else {
+        return;
+    };
+    if state.get() == error_state {
+        // The error state can itself be tracked, and the errors are still
+        // there if the tracker was not cleared. Do not enter it again.
+        return;
+    }
+    // If there are several errors, report the one from the oldest entry.
+    let mut first_error: Option<(ProgressEntryId, String)> = None;
+    gpt.foreach_error(|id, message| {
+        if first_error.as_ref().is_none_or(|(first, _)| id < *first) {
+            first_error = Some((id, message.to_owned()));
+        }
+    });
+    let Some((id, message)) = first_error else {
+        return;
+    };
+    #[cfg(feature = "debug")]
+    debug!(
+        "Progress error: {}! Transitioning to state {:?}",
+        message, error_state
+    );
+    next_state.set(error_state.clone());
+    commands.insert_resource(ProgressFailure::<S> {
+        state: state.get().clone(),
+        id,
+        message,
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transition_if_ready<S: ProgressContext>(
+    gpt: Res<ProgressTracker<S>>,
+    displayed: Option<Res<DisplayedProgress<S>>>,
+    mut config: ResMut<StateTransitionConfig<S>>,
+    state: Res<State<S::State>>,
+    mut next_state: ResMut<NextState<S::State>>,
+    mut commands: Commands,
+    mut evw_complete: EventWriter<ProgressComplete<S>>,
+    entered: Option<Res<StateEnteredAt<S>>>,
+    time: Option<Res<Time<Virtual>>>,
+) where
+    S::State: FreelyMutableState,
+{
+    let config = config.bypass_change_detection();
+    let Some(to) = config.map_from_to.get(state.get()).cloned() else {
+        return;
+    };
+    let mut ready = match config.exit_gate {
+        // The gate must exist and be ready, in addition to everything else.
+        Some(gate) => gpt.is_ready_except(gate) && gpt.is_id_ready(gate),
+        None => gpt.is_ready(),
+    };
+    if config.require_registered {
+        // Nothing has been reported yet, so the tracker is only "ready"
+        // because it is empty.
+        let registered = gpt.read(|view| {
+            view.entry_count() > 0
+                || view.get_global_combined_progress().total > 0
+        });
+        ready &= registered;
+    }
+    if let (Some(min), Some(entered), Some(time)) =
+        (config.min_duration, entered, time)
+    {
+        // Keep the loading screen up for at least this long.
+        if time.elapsed().saturating_sub(entered.elapsed) < min {
+            ready = false;
+        }
+    }
+    if config.visible_first {
+        // The visible progress must have already been complete when we
+        // last checked, so that it had a chance to be displayed.
+        let visible_ready = gpt.get_global_progress().is_ready();
+        let was_shown = config.visible_shown;
+        config.visible_shown = visible_ready;
+        ready &= visible_ready && was_shown;
+    }
+    if displayed.is_some_and(|d| !d.is_ready()) {
+        ready = false;
+    }
+    if ready {
+        // Do not clobber a transition that someone else has requested
+        // (for example, a redirect to an error state).
+        if let NextState::Pending(pending) = &*next_state {
+            if !config.queued || *pending != to {
+                #[cfg(feature = "debug")]
+                debug!(
+                    "Progress complete, but a transition to state {:?} is \
+                     already pending. Not transitioning to state {:?}.",
+                    pending, to
+                );
+                return;
+            }
+        }
+        if let Some(applier) = &config.applier {
+            // A custom applier is not necessarily idempotent (for example,
+            // it might push onto a stack), so only call it once.
+            if config.queued {
+                return;
+            }
+            let applier = applier.clone();
+            let to = to.clone();
+            gpt.insert_transition_payloads(&mut commands);
+            commands.queue(move |world: &mut World| applier(world, &to));
+        } else {
             next_state.set(to.clone());
+            gpt.insert_transition_payloads(&mut commands);
+        }
+        if !config.queued {
+            evw_complete.send(ProgressComplete {
+                from: state.get().clone(),
+                to: to.clone(),
+            });
+        }
+        config.queued = true;
+        #[cfg(feature = "debug")]
+        debug!("Progress complete! Transitioning to state {:?}", to);
+    } else if config.queued && config.applier.is_none() {
+        // We queued a transition, but more work has appeared since then.
+        // If the transition has not happened yet, cancel it. A custom
+        // transition cannot be cancelled, so it stays queued until the
+        // state is entered again.
+        if matches!(&*next_state, NextState::Pending(s) if *s == to) {
+            next_state.reset();
             #[cfg(feature = "debug")]
-            debug!("Progress complete! Transitioning to state {:?}", to);
+            debug!("Progress no longer complete! Cancelling transition.");
         }
+        config.queued = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_state::prelude::*;
+
+    use super::*;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum MyStates {
+        #[default]
+        Loading,
+        Done,
+        Redirect,
+        Failed,
+    }
+
+    fn check_now(app: &mut ProgressTestApp<MyStates>) -> bool {
+        check_and_transition_now::<MyStates>(app.app_mut().world_mut())
+    }
+
+    #[test]
+    fn transitions_when_ready() {
+        let mut app =
+            ProgressTestApp::<MyStates>::new(MyStates::Loading, MyStates::Done);
+        app.step_n(2).assert_state(MyStates::Done);
+    }
+
+    #[test]
+    fn does_not_overwrite_pending_transition() {
+        let mut app =
+            ProgressTestApp::<MyStates>::new(MyStates::Loading, MyStates::Done);
+        app.app_mut().add_systems(
+            bevy_app::Update,
+            (|mut next: ResMut<NextState<MyStates>>| {
+                next.set(MyStates::Redirect);
+            })
+            .run_if(in_state(MyStates::Loading)),
+        );
+        app.step_n(2).assert_state(MyStates::Redirect);
+    }
+
+    #[test]
+    fn minimum_duration_does_not_elapse_while_paused() {
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_minimum_duration(Duration::from_secs(1)),
+        );
+        app.step();
+        app.app_mut()
+            .world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .pause();
+        app.step_n(120).assert_state(MyStates::Loading);
+        app.app_mut()
+            .world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .unpause();
+        app.step_n(30).assert_state(MyStates::Loading);
+        app.step_n(40).assert_state(MyStates::Done);
+    }
+
+    #[test]
+    fn check_now_transitions_immediately() {
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(id, 1),
+        );
+        app.step();
+        assert!(!check_now(&mut app));
+        app.assert_state(MyStates::Loading);
+        app.set_done(id, 1);
+        assert!(check_now(&mut app));
+        app.assert_state(MyStates::Done);
+        assert!(!check_now(&mut app));
+    }
+
+    #[test]
+    fn check_now_shares_visible_first_state() {
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(id, 1)
+                .with_visible_first(true),
+        );
+        app.step();
+        app.tracker().set_hidden_progress(id, 0, 1);
+        app.set_done(id, 1);
+        // The automatic check sees the complete visible progress first...
+        app.step().assert_state(MyStates::Loading);
+        // ...so the hidden progress is all that is left.
+        app.tracker().set_hidden_progress(id, 1, 1);
+        assert!(check_now(&mut app));
+        app.assert_state(MyStates::Done);
+    }
+
+    #[test]
+    fn check_now_transitions_on_error() {
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_error_state(MyStates::Failed),
+        );
+        app.step();
+        app.tracker().set_error(id, "oops");
+        assert!(check_now(&mut app));
+        app.assert_state(MyStates::Failed);
+        let failure = app.app().world().resource::<ProgressFailure<MyStates>>();
+        assert_eq!(failure.message, "oops");
+    }
+
+    #[test]
+    fn check_now_calls_applier_once() {
+        #[derive(Resource, Default)]
+        struct Calls(u32);
+
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(id, 1)
+                .with_transition_applier(|world, _| {
+                    world.resource_mut::<Calls>().0 += 1;
+                }),
+        );
+        app.app_mut().init_resource::<Calls>();
+        app.step();
+        app.set_done(id, 1);
+        assert!(check_now(&mut app));
+        assert!(!check_now(&mut app));
+        app.step_n(2).assert_state(MyStates::Loading);
+        assert_eq!(app.app().world().resource::<Calls>().0, 1);
+    }
+
+    #[test]
+    fn applier_is_called_once_if_progress_drops() {
+        #[derive(Resource, Default)]
+        struct Calls(u32);
+
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_reservation(id, 1)
+                .with_transition_applier(|world, _| {
+                    world.resource_mut::<Calls>().0 += 1;
+                }),
+        );
+        app.app_mut().init_resource::<Calls>();
+        app.step();
+        app.set_done(id, 1).step_n(2);
+        assert_eq!(app.app().world().resource::<Calls>().0, 1);
+        app.set_done(id, 0).step_n(2);
+        app.set_done(id, 1).step_n(2);
+        assert_eq!(app.app().world().resource::<Calls>().0, 1);
+    }
+
+    #[test]
+    fn error_state_is_entered_once() {
+        #[derive(Resource, Default)]
+        struct Entered(usize);
+
+        let id = ProgressEntryId::new();
+        let mut app = ProgressTestApp::<MyStates>::with_plugin(
+            MyStates::Loading,
+            ProgressPlugin::new()
+                .with_state_transition(MyStates::Loading, MyStates::Done)
+                .with_tracked_state(MyStates::Failed)
+                .with_error_state(MyStates::Failed)
+                .auto_clear(false, false),
+        );
+        // Transitions to the same state do not run `OnEnter`, so count the
+        // transition events instead.
+        app.app_mut().init_resource::<Entered>().add_systems(
+            bevy_app::Update,
+            |mut evr: EventReader<StateTransitionEvent<MyStates>>,
+             mut entered: ResMut<Entered>| {
+                entered.0 += evr
+                    .read()
+                    .filter(|ev| ev.entered == Some(MyStates::Failed))
+                    .count();
+            },
+        );
+        app.set_progress(id, 0, 1).step();
+        app.tracker().set_error(id, "oops");
+        app.step_n(5).assert_state(MyStates::Failed);
+        assert_eq!(app.app().world().resource::<Entered>().0, 1);
     }
 }
@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::SystemConfigs;
-use bevy_state::state::FreelyMutableState;
+use bevy_time::{Time, Virtual};
+use bevy_utils::all_tuples;
 
 use crate::prelude::*;
 
@@ -16,12 +19,95 @@ pub trait ProgressReturningSystem<T, Params> {
     /// Note: it is OK if your system does not run every frame (for example,
     /// if you have run conditions). The value from when the system last ran
     /// will be retained until your system runs again.
-    fn track_progress<S: FreelyMutableState>(self) -> SystemConfigs;
+    ///
+    /// Your system can take any parameters that a normal Bevy system can,
+    /// including `Commands`, so it can do its work and report its progress
+    /// at the same time. Only the states type needs to be specified:
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct TreeAssets;
+    /// # #[derive(Component)]
+    /// # struct Tree;
+    /// # impl Tree {
+    /// #     fn new(_assets: &TreeAssets) -> Self {
+    /// #         Tree
+    /// #     }
+    /// # }
+    /// # let mut app = App::new();
+    /// fn spawn_trees(
+    ///     mut commands: Commands,
+    ///     assets: Res<TreeAssets>,
+    ///     mut spawned: Local<u32>,
+    /// ) -> Progress {
+    ///     if *spawned < 100 {
+    ///         commands.spawn(Tree::new(&assets));
+    ///         *spawned += 1;
+    ///     }
+    ///     Progress::new(*spawned, 100)
+    /// }
+    ///
+    /// app.add_systems(
+    ///     Update,
+    ///     spawn_trees
+    ///         .track_progress::<MyStates>()
+    ///         .run_if(in_state(MyStates::Loading)),
+    /// );
+    /// ```
+    ///
+    /// Your system can also return a `Result` (of any of the progress
+    /// types), if it can fail. An `Err` is recorded as an error on the entry
+    /// (see [`ProgressStore::set_error`]). To leave the loading state as
+    /// soon as that happens, see
+    /// [`with_error_state`](ProgressPlugin::with_error_state).
+    fn track_progress<S: ProgressContext>(self) -> SystemConfigs;
 
     /// Like [`track_progress`](Self::track_progress), but adds a run condition
     /// to no longer run the system after it has returned a fully ready
     /// progress value.
-    fn track_progress_and_stop<S: FreelyMutableState>(self) -> SystemConfigs;
+    fn track_progress_and_stop<S: ProgressContext>(self) -> SystemConfigs;
+
+    /// Like [`track_progress`](Self::track_progress), but only runs your
+    /// system once every `n_frames` frames (starting with the first frame).
+    ///
+    /// Useful if your system is expensive. The value from when the system
+    /// last ran is retained in between. If the [`ProgressTracker`] is
+    /// cleared, the system runs again on the next frame.
+    fn track_progress_every<S: ProgressContext>(
+        self,
+        n_frames: u32,
+    ) -> SystemConfigs;
+
+    /// Like [`track_progress`](Self::track_progress), but only counts as
+    /// ready after your system has been returning a fully ready progress
+    /// value continuously for at least `stable_for`.
+    ///
+    /// Useful if your readiness condition can flicker (for example, "are all
+    /// players connected", when a player briefly disconnects), to avoid
+    /// premature state transitions.
+    ///
+    /// `stable_for` is measured in virtual time (`Time<Virtual>`), so it
+    /// does not elapse while the app is paused, even if your system runs in
+    /// a different schedule (like `FixedUpdate`). If your app has no
+    /// `Time<Virtual>` (it does not use Bevy's `TimePlugin`), there is no
+    /// debouncing: the progress is ready as soon as your system returns a
+    /// ready value.
+    ///
+    /// This is implemented using an additional entry with a single item of
+    /// hidden progress, which is only marked as done once the progress
+    /// returned by your system has been stable for long enough.
+    fn track_progress_debounced<S: ProgressContext>(
+        self,
+        stable_for: Duration,
+    ) -> SystemConfigs;
 }
 
 impl<S, T, Params> ProgressReturningSystem<T, Params> for S
@@ -29,7 +115,7 @@ where
     S: IntoSystem<(), T, Params>,
     T: ApplyProgress + 'static,
 {
-    fn track_progress<State: FreelyMutableState>(self) -> SystemConfigs {
+    fn track_progress<State: ProgressContext>(self) -> SystemConfigs {
         let id = ProgressEntryId::new();
         self.pipe(
             move |In(progress): In<T>, tracker: Res<ProgressTracker<State>>| {
@@ -39,9 +125,7 @@ where
         .into_configs()
     }
 
-    fn track_progress_and_stop<State: FreelyMutableState>(
-        self,
-    ) -> SystemConfigs {
+    fn track_progress_and_stop<State: ProgressContext>(self) -> SystemConfigs {
         let id = ProgressEntryId::new();
         self.pipe(
             move |In(progress): In<T>, tracker: Res<ProgressTracker<State>>| {
@@ -53,8 +137,127 @@ where
         })
         .into_configs()
     }
+
+    fn track_progress_every<State: ProgressContext>(
+        self,
+        n_frames: u32,
+    ) -> SystemConfigs {
+        let n_frames = n_frames.max(1);
+        let id = ProgressEntryId::new();
+        self.pipe(
+            move |In(progress): In<T>, tracker: Res<ProgressTracker<State>>| {
+                progress.apply_progress(&tracker, id);
+            },
+        )
+        .run_if(
+            move |tracker: Res<ProgressTracker<State>>,
+                  mut counter: Local<u32>| {
+                // If the progress data was cleared, report again right away,
+                // rather than leaving the entry missing until our next turn.
+                if !tracker.contains_id(id) {
+                    *counter = 0;
+                }
+                let run = *counter == 0;
+                *counter = (*counter + 1) % n_frames;
+                run
+            },
+        )
+        .into_configs()
+    }
+
+    fn track_progress_debounced<State: ProgressContext>(
+        self,
+        stable_for: Duration,
+    ) -> SystemConfigs {
+        let id = ProgressEntryId::new();
+        let gate_id = ProgressEntryId::new();
+        self.pipe(
+            move |In(progress): In<T>,
+                  tracker: Res<ProgressTracker<State>>,
+                  time: Option<Res<Time<Virtual>>>,
+                  mut ready_since: Local<Option<Duration>>| {
+                // Start over if the progress data was cleared since we last
+                // ran (e.g. when re-entering the state).
+                if !tracker.contains_id(gate_id) {
+                    *ready_since = None;
+                }
+                let stable = match time {
+                    Some(time) => {
+                        let now = time.elapsed();
+                        if progress.is_ready() {
+                            ready_since.get_or_insert(now);
+                        } else {
+                            *ready_since = None;
+                        }
+                        ready_since.is_some_and(|since| {
+                            now.saturating_sub(since) >= stable_for
+                        })
+                    }
+                    // Without time, we cannot debounce.
+                    None => progress.is_ready(),
+                };
+                progress.apply_progress(&tracker, id);
+                tracker.set_hidden_progress(gate_id, stable as u32, 1);
+            },
+        )
+        .into_configs()
+    }
+}
+
+/// Extension trait to track many systems returning progress at once.
+///
+/// Implemented for tuples of systems (up to 16). Every system is tracked
+/// individually (with its own entry), as if you had called
+/// [`track_progress`](ProgressReturningSystem::track_progress) on each one.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # fn load_map() -> Progress {
+/// #     Progress::default()
+/// # }
+/// # fn connect_to_server() -> Progress {
+/// #     Progress::default()
+/// # }
+/// # fn warm_up_shaders() -> Progress {
+/// #     Progress::default()
+/// # }
+/// # let mut app = App::new();
+/// app.add_systems(Update,
+///     (load_map, connect_to_server, warm_up_shaders)
+///         .track_all::<MyStates>()
+///         .run_if(in_state(MyStates::Loading))
+/// );
+/// ```
+pub trait ProgressReturningSystems<Marker> {
+    /// Track every system in the tuple, each with its own entry.
+    fn track_all<S: ProgressContext>(self) -> SystemConfigs;
+}
+
+macro_rules! impl_progress_returning_systems {
+    ($(($sys:ident, $t:ident, $params:ident)),*) => {
+        impl<$($sys, $t, $params),*>
+            ProgressReturningSystems<($(($t, $params),)*)> for ($($sys,)*)
+        where
+            $($sys: ProgressReturningSystem<$t, $params>),*
+        {
+            #[allow(non_snake_case)]
+            fn track_all<State: ProgressContext>(self) -> SystemConfigs {
+                let ($($sys,)*) = self;
+                ($($sys.track_progress::<State>(),)*).into_configs()
+            }
+        }
+    };
 }
 
+all_tuples!(impl_progress_returning_systems, 1, 16, F, T, P);
+
 /// Adapter for converting a system returning [`Progress`] into
 /// [`HiddenProgress`]
 ///
@@ -84,3 +287,87 @@ pub fn hide_progress(In(progress): In<Progress>) -> HiddenProgress {
 pub fn unhide_progress(In(progress): In<HiddenProgress>) -> Progress {
     progress.0
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_state::prelude::*;
+
+    use super::*;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum MyStates {
+        #[default]
+        Loading,
+        Done,
+    }
+
+    #[test]
+    fn entries_come_back_after_clear() {
+        let mut world = World::new();
+        world.init_resource::<ProgressTracker<MyStates>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            (|| Progress::new(1, 2)).track_progress::<MyStates>(),
+            (|| Progress::new(3, 3)).track_progress_and_stop::<MyStates>(),
+            (|| HiddenProgress::new(0, 4)).track_progress_every::<MyStates>(3),
+            |pe: ProgressEntry<MyStates>| pe.set_progress(5, 6),
+        ));
+
+        let sums = |world: &World| {
+            world.resource::<ProgressTracker<MyStates>>().read(|view| {
+                (
+                    view.entry_count(),
+                    view.get_global_progress(),
+                    view.get_global_hidden_progress(),
+                )
+            })
+        };
+        let expected = (4, Progress::new(9, 11), HiddenProgress::new(0, 4));
+        schedule.run(&mut world);
+        assert_eq!(sums(&world), expected);
+
+        world.resource::<ProgressTracker<MyStates>>().clear();
+        assert_eq!(sums(&world).0, 0);
+
+        // The throttled and stopped systems must report again right away.
+        schedule.run(&mut world);
+        assert_eq!(sums(&world), expected);
+    }
+
+    #[test]
+    fn debounce_does_not_elapse_while_paused() {
+        let mut app =
+            ProgressTestApp::<MyStates>::new(MyStates::Loading, MyStates::Done);
+        app.app_mut().add_systems(
+            bevy_app::Update,
+            (|| Progress::new(1, 1))
+                .track_progress_debounced::<MyStates>(Duration::from_secs(1))
+                .run_if(in_state(MyStates::Loading)),
+        );
+        app.step();
+        app.app_mut()
+            .world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .pause();
+        app.step_n(120).assert_state(MyStates::Loading);
+        app.app_mut()
+            .world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .unpause();
+        app.step_n(30).assert_state(MyStates::Loading);
+        app.step_n(40).assert_state(MyStates::Done);
+    }
+
+    #[test]
+    fn debounce_without_time() {
+        let mut world = World::new();
+        world.init_resource::<ProgressTracker<MyStates>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (|| Progress::new(1, 1))
+                .track_progress_debounced::<MyStates>(Duration::from_secs(1)),
+        );
+        schedule.run(&mut world);
+        assert!(world.resource::<ProgressTracker<MyStates>>().is_ready());
+    }
+}
@@ -0,0 +1,143 @@
+//! Helpers for testing apps that use progress tracking
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_state::app::{AppExtStates, StatesPlugin};
+use bevy_state::state::{FreelyMutableState, State};
+use bevy_time::{TimePlugin, TimeUpdateStrategy};
+
+use crate::prelude::*;
+
+/// A minimal [`App`] with progress tracking, for use in tests.
+///
+/// It has only what is needed for progress tracking (states and time), and
+/// time advances by a fixed step every update (1/60th of a second), so
+/// tests are deterministic.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// let id = ProgressEntryId::new();
+/// let mut app = ProgressTestApp::<MyStates>::with_plugin(
+///     MyStates::Loading,
+///     ProgressPlugin::new()
+///         .with_state_transition(MyStates::Loading, MyStates::Done)
+///         .with_reservation(id, 2),
+/// );
+/// app.step().assert_state(MyStates::Loading);
+/// app.set_done(id, 2).step_n(2);
+/// app.assert_state(MyStates::Done);
+/// ```
+///
+/// Remember that the initial state is entered (and so, the tracker is
+/// cleared) during the first [`step`](Self::step), and that a tracker with
+/// nothing in it counts as ready. Your work must be registered in the first
+/// frame, or the state transition will happen right away (unless you
+/// configure the plugin with
+/// [`require_registered_progress`](ProgressPlugin::require_registered_progress)).
+///
+/// Use [`app_mut`](Self::app_mut) to add your own systems and resources,
+/// before the first step.
+pub struct ProgressTestApp<S: ProgressContext> {
+    app: App,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> ProgressTestApp<S>
+where
+    S::State: FreelyMutableState,
+{
+    /// Create a new test app, starting in the `from` state, with progress
+    /// tracking configured to transition to the `to` state.
+    pub fn new(from: S::State, to: S::State) -> Self {
+        let plugin =
+            ProgressPlugin::<S>::new().with_state_transition(from.clone(), to);
+        Self::with_plugin(from, plugin)
+    }
+
+    /// Create a new test app, starting in the `initial` state, with a
+    /// [`ProgressPlugin`] you have configured yourself.
+    pub fn with_plugin(initial: S::State, plugin: ProgressPlugin<S>) -> Self {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, TimePlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs(1) / 60,
+        ));
+        app.insert_state(initial);
+        app.add_plugins(plugin);
+        Self {
+            app,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Access the underlying [`App`].
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Access the underlying [`App`] mutably.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Run one update (frame) of the app.
+    pub fn step(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+
+    /// Run `n` updates (frames) of the app.
+    pub fn step_n(&mut self, n: u32) -> &mut Self {
+        for _ in 0..n {
+            self.app.update();
+        }
+        self
+    }
+
+    /// Access the [`ProgressTracker<S>`].
+    pub fn tracker(&self) -> &ProgressTracker<S> {
+        self.app.world().resource::<ProgressTracker<S>>()
+    }
+
+    /// Overwrite the stored (visible) progress for a specific ID.
+    ///
+    /// See [`ProgressStore::set_progress`].
+    pub fn set_progress(
+        &mut self,
+        id: ProgressEntryId,
+        done: u32,
+        total: u32,
+    ) -> &mut Self {
+        self.tracker().set_progress(id, done, total);
+        self
+    }
+
+    /// Overwrite the stored (visible) completed work items for a specific ID.
+    ///
+    /// See [`ProgressStore::set_done`].
+    pub fn set_done(&mut self, id: ProgressEntryId, done: u32) -> &mut Self {
+        self.tracker().set_done(id, done);
+        self
+    }
+
+    /// Get the current state.
+    pub fn state(&self) -> &S::State {
+        self.app.world().resource::<State<S::State>>().get()
+    }
+
+    /// Panic if the current state is not `expected`.
+    #[track_caller]
+    pub fn assert_state(&self, expected: S::State) -> &Self {
+        assert_eq!(self.state(), &expected, "unexpected state");
+        self
+    }
+}
@@ -1,18 +1,30 @@
 //! Storing and tracking progress
 
+use std::any::Any;
+use std::collections::{BTreeMap, VecDeque};
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::marker::PhantomData;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[cfg(feature = "async")]
+use std::sync::Arc;
+use std::time::Duration;
 
 use bevy_ecs::prelude::*;
 use bevy_ecs::system::SystemParam;
-use bevy_state::state::FreelyMutableState;
-use bevy_utils::HashMap;
+use bevy_time::{Time, Virtual};
+use bevy_utils::{HashMap, HashSet};
 use parking_lot::Mutex;
 
 use crate::prelude::*;
 
 static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
 
+/// IDs allocated by [`ProgressStore::new_id`] have this bit set, so that
+/// they never collide with IDs from the global counter.
+const LOCAL_ID_BIT: usize = 1 << (usize::BITS - 1);
+
 /// An opaque ID for accessing data stored in the [`ProgressTracker`].
 ///
 /// The ID can be used with the [`ProgressTracker`] resource
@@ -26,17 +38,46 @@ static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
 /// You can create a new unique ID at any time by calling
 /// [`ProgressEntryId::new()`]. Store that ID and then use it to update the
 /// values in the [`ProgressTracker`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct ProgressEntryId(usize);
+///
+/// IDs are ordered by creation: an ID created later compares greater.
+///
+/// Alternatively, you can allocate IDs from a specific tracker, using
+/// [`ProgressStore::new_id`]. Such IDs always compare greater than IDs
+/// created by [`ProgressEntryId::new()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProgressEntryId(pub(crate) usize);
 
 impl ProgressEntryId {
     /// Create a new unique ID
+    #[allow(clippy::new_without_default)]
     pub fn new() -> ProgressEntryId {
         let next_id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
         ProgressEntryId(next_id)
     }
 }
 
+/// Event sent the first time a specific entry in the
+/// [`ProgressTracker<S>`] is ready.
+///
+/// Useful for analytics (for example, to find out in which order your
+/// loading tasks finish). "Ready" is the same as
+/// [`is_id_ready`](ProgressStore::is_id_ready), so it accounts for custom
+/// predicates and the [`ErrorPolicy`].
+///
+/// Each entry only sends this once, even if more work is added to it
+/// afterwards, until the tracker is cleared (for example, when the state is
+/// re-entered). Entries are checked once per frame, in
+/// [`CheckProgressSet`], while in a progress-tracked state.
+#[derive(Event, Debug, Clone)]
+pub struct ProgressEntryComplete<S: ProgressContext> {
+    /// The entry that is now ready.
+    pub id: ProgressEntryId,
+    /// The label of the entry, if it has one (see
+    /// [`set_label`](ProgressStore::set_label)).
+    pub label: Option<String>,
+    _pd: PhantomData<S>,
+}
+
 /// The resource where all the progress information is stored.
 ///
 /// You can get information about the overall accumulated progress
@@ -49,77 +90,817 @@ impl ProgressEntryId {
 ///
 /// All stored values are cleared automatically when entering a
 /// state configured for progress tracking. You can reset everything
-/// manually by calling [`clear`](Self::clear).
+/// manually by calling [`clear`](ProgressStore::clear).
+///
+/// This is a thin wrapper around a [`ProgressStore`], which is where all
+/// the methods are. The `S` parameter ties it to your states type (or
+/// [`ProgressContext`]), so that the [`ProgressPlugin`] can manage it.
+#[derive(Resource)]
+pub struct ProgressTracker<S: ProgressContext> {
+    store: ProgressStore,
+    _pd: PhantomData<S>,
+}
+
+impl<S: ProgressContext> Default for ProgressTracker<S> {
+    fn default() -> Self {
+        Self {
+            store: Default::default(),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<S: ProgressContext> Deref for ProgressTracker<S> {
+    type Target = ProgressStore;
+
+    fn deref(&self) -> &ProgressStore {
+        &self.store
+    }
+}
+
+impl<S: ProgressContext> DerefMut for ProgressTracker<S> {
+    fn deref_mut(&mut self) -> &mut ProgressStore {
+        &mut self.store
+    }
+}
+
+/// The core of progress tracking, without any states type.
+///
+/// This accumulates [`Progress`] and [`HiddenProgress`] for any number of
+/// entries, just like a [`ProgressTracker<S>`] (which wraps it), but is not
+/// tied to Bevy states in any way. Use it on its own, if you want to track
+/// progress for something that is not a loading state (like a background
+/// indexer):
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// #[derive(Resource)]
+/// struct Indexer {
+///     progress_id: ProgressEntryId,
+///     files: Vec<String>,
+///     indexed: usize,
+/// }
+///
+/// fn index_files(store: Res<ProgressStore>, mut indexer: ResMut<Indexer>) {
+///     if indexer.indexed < indexer.files.len() {
+///         // ... index the next file ...
+///         indexer.indexed += 1;
+///     }
+///     store.set_progress(
+///         indexer.progress_id,
+///         indexer.indexed as u32,
+///         indexer.files.len() as u32,
+///     );
+/// }
+///
+/// let mut app = App::new();
+/// app.init_resource::<ProgressStore>();
+/// app.insert_resource(Indexer {
+///     progress_id: ProgressEntryId::new(),
+///     files: vec!["a.txt".into(), "b.txt".into()],
+///     indexed: 0,
+/// });
+/// app.add_systems(Update, index_files);
+/// ```
+///
+/// Nothing happens automatically with a standalone store: it is never
+/// cleared, and there are no state transitions. The [`ProgressPlugin`] and
+/// the APIs built on top of it ([`ProgressEntry`], systems returning
+/// progress, asset tracking) work with [`ProgressTracker<S>`] instead.
 #[derive(Resource)]
-pub struct ProgressTracker<S: FreelyMutableState> {
+pub struct ProgressStore {
     inner: Mutex<GlobalProgressTrackerInner>,
+    next_local_id: AtomicUsize,
+    keys: Mutex<HashMap<String, ProgressEntryId>>,
+    skip_next_clear: AtomicBool,
     #[cfg(feature = "async")]
-    pub(crate) chan: Option<(Sender, Receiver)>,
-    _pd: PhantomData<S>,
+    pub(crate) chan: Mutex<Option<(Sender, Receiver)>>,
+    #[cfg(feature = "async")]
+    cancelled: Mutex<Arc<AtomicBool>>,
+    #[cfg(feature = "async")]
+    completion_waiters: Mutex<Vec<futures_channel::oneshot::Sender<()>>>,
 }
 
-impl<S: FreelyMutableState> Default for ProgressTracker<S> {
+impl Default for ProgressStore {
     fn default() -> Self {
         Self {
             inner: Default::default(),
+            next_local_id: AtomicUsize::new(0),
+            keys: Default::default(),
+            skip_next_clear: AtomicBool::new(false),
             #[cfg(feature = "async")]
-            chan: None,
-            _pd: PhantomData,
+            chan: Default::default(),
+            #[cfg(feature = "async")]
+            cancelled: Default::default(),
+            #[cfg(feature = "async")]
+            completion_waiters: Default::default(),
         }
     }
 }
 
+type ReadyPredicate = Box<dyn Fn(Progress) -> bool + Send + Sync>;
+type TransitionPayload = Box<dyn FnOnce(&mut Commands) + Send>;
+
+/// How far back in time we look, when estimating the rate of progress for
+/// [`ProgressStore::entry_eta`].
+const ETA_WINDOW: Duration = Duration::from_secs(3);
+
+/// Samples of `(time, combined progress)` for an entry, oldest first.
+type EtaSamples = VecDeque<(Duration, Progress)>;
+
+/// Never report `100` percent for work that is not ready.
+fn cap_percent(percent: u8, ready: bool) -> u8 {
+    if ready {
+        percent
+    } else {
+        percent.min(99)
+    }
+}
+
+/// Round weighted sums to a [`Progress`].
+///
+/// If the work is not complete, `done` never rounds up to `total`.
+fn weighted_progress(done: f64, total: f64) -> Progress {
+    let mut p = Progress {
+        done: done.round() as u32,
+        total: total.round() as u32,
+    };
+    if done < total && p.done >= p.total {
+        p.done = p.total.saturating_sub(1);
+    }
+    p
+}
+
+/// Scale `u64` progress values down, so that they fit in a [`Progress`].
+///
+/// Both values are divided by the same power of two. If the work is not
+/// complete, the result is never rounded up to complete.
+fn scale_u64_progress(done: u64, total: u64) -> Progress {
+    let shift = (u64::BITS - total.leading_zeros()).saturating_sub(u32::BITS);
+    let total_scaled = (total >> shift) as u32;
+    let mut done_scaled = (done >> shift).min(u32::MAX as u64) as u32;
+    if done < total {
+        done_scaled = done_scaled.min(total_scaled - 1);
+    }
+    Progress {
+        done: done_scaled,
+        total: total_scaled,
+    }
+}
+
+/// What the progress shown to the user should include.
+///
+/// See [`ProgressStore::get_displayed_progress`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    /// Only show the visible progress. Hidden progress is not shown.
+    #[default]
+    VisibleOnly,
+    /// Show both the visible and hidden progress, combined.
+    Combined,
+}
+
+/// The overall state of the tracked work.
+///
+/// See [`ProgressStore::completion_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionState {
+    /// No work has been registered (the total is zero). This is also
+    /// considered "ready", but it usually means nothing has reported
+    /// progress yet.
+    Empty,
+    /// There is work that is not complete yet.
+    InProgress,
+    /// All the work is complete.
+    Complete,
+}
+
+/// How entries that have an error (see [`ProgressStore::set_error`])
+/// affect readiness.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Errored entries are never ready, so the overall progress is never
+    /// ready either (and no state transition will happen) while there are
+    /// any errors.
+    #[default]
+    Block,
+    /// Errored entries are considered ready, regardless of their progress
+    /// values.
+    CountAsDone,
+}
+
 #[derive(Default)]
 struct GlobalProgressTrackerInner {
-    entries: HashMap<ProgressEntryId, (Progress, HiddenProgress)>,
+    entries: BTreeMap<ProgressEntryId, (Progress, HiddenProgress)>,
     sum_entities: (Progress, HiddenProgress),
     sum_entries: (Progress, HiddenProgress),
+    metadata: HashMap<ProgressEntryId, Box<dyn Any + Send + Sync>>,
+    predicates: HashMap<ProgressEntryId, ReadyPredicate>,
+    errors: HashMap<ProgressEntryId, String>,
+    statuses: HashMap<ProgressEntryId, String>,
+    labels: HashMap<ProgressEntryId, String>,
+    eta_samples: HashMap<ProgressEntryId, EtaSamples>,
+    wide: HashMap<ProgressEntryId, (u64, u64)>,
+    dependencies: HashMap<ProgressEntryId, Vec<ProgressEntryId>>,
+    weights: HashMap<ProgressEntryId, f32>,
+    completed: HashSet<ProgressEntryId>,
+    payloads: Vec<TransitionPayload>,
+    error_policy: ErrorPolicy,
+    display_mode: DisplayMode,
+}
+
+impl GlobalProgressTrackerInner {
+    fn global_progress(&self) -> Progress {
+        self.weighted_sums().0
+    }
+
+    fn global_hidden_progress(&self) -> HiddenProgress {
+        self.weighted_sums().1
+    }
+
+    fn global_combined_progress(&self) -> Progress {
+        let (v, h) = self.weighted_sums();
+        v.saturating_add(h.0)
+    }
+
+    /// The sums of all the entries and entities, with every entry counted
+    /// equally (ignoring weights).
+    fn raw_sums(&self) -> (Progress, HiddenProgress) {
+        (
+            self.sum_entries.0.saturating_add(self.sum_entities.0),
+            self.sum_entries.1.saturating_add(self.sum_entities.1),
+        )
+    }
+
+    fn raw_combined_progress(&self) -> Progress {
+        let (v, h) = self.raw_sums();
+        v.saturating_add(h.0)
+    }
+
+    /// The sums of all the entries and entities, with each entry scaled
+    /// by its weight (see [`ProgressStore::set_weight`]).
+    fn weighted_sums(&self) -> (Progress, HiddenProgress) {
+        let raw = self.raw_sums();
+        if self.weights.is_empty() {
+            return raw;
+        }
+        // Start from the raw sums, and only adjust the weighted entries.
+        let mut v = (raw.0.done as f64, raw.0.total as f64);
+        let mut h = (raw.1.done as f64, raw.1.total as f64);
+        for (id, weight) in self.weights.iter() {
+            let Some((ev, eh)) = self.entries.get(id) else {
+                continue;
+            };
+            let extra = *weight as f64 - 1.0;
+            v.0 += extra * ev.done as f64;
+            v.1 += extra * ev.total as f64;
+            h.0 += extra * eh.done as f64;
+            h.1 += extra * eh.total as f64;
+        }
+        (
+            weighted_progress(v.0, v.1),
+            weighted_progress(h.0, h.1).into(),
+        )
+    }
+
+    fn displayed_progress(&self) -> Progress {
+        match self.display_mode {
+            DisplayMode::VisibleOnly => self.global_progress(),
+            DisplayMode::Combined => self.global_combined_progress(),
+        }
+    }
+
+    fn combined_progress(&self, id: ProgressEntryId) -> Option<Progress> {
+        self.entries.get(&id).map(|x| x.0.saturating_add(x.1 .0))
+    }
+
+    fn is_ready(&self) -> bool {
+        self.is_ready_except(None)
+    }
+
+    /// Check readiness, ignoring the given entry (if any).
+    ///
+    /// This sums up the entries that are not checked individually, rather
+    /// than subtracting from the global sums, because those saturate.
+    fn is_ready_except(&self, except: Option<ProgressEntryId>) -> bool {
+        let excluded = |id: &ProgressEntryId| except == Some(*id);
+        let count_as_done = |id: &ProgressEntryId| {
+            self.error_policy == ErrorPolicy::CountAsDone
+                && self.errors.contains_key(id)
+        };
+        if self.error_policy == ErrorPolicy::Block
+            && self.errors.keys().any(|id| !excluded(id))
+        {
+            return false;
+        }
+        // Custom predicates also apply to entries that do not exist yet.
+        for (id, predicate) in self.predicates.iter() {
+            if !excluded(id)
+                && !count_as_done(id)
+                && !self.entries.contains_key(id)
+                && !predicate(Progress::default())
+            {
+                return false;
+            }
+        }
+        let (v, h) = self.sum_entities;
+        let mut rest = v.saturating_add(h.0);
+        for (id, (v, h)) in self.entries.iter() {
+            if excluded(id) || count_as_done(id) {
+                continue;
+            }
+            let p = v.saturating_add(h.0);
+            if let Some(predicate) = self.predicates.get(id) {
+                // Entries with a custom predicate are checked individually.
+                if !predicate(p) {
+                    return false;
+                }
+            } else if self.weights.contains_key(id) {
+                // Weighted entries must each be complete on their own, so
+                // that overshooting in one entry cannot make up for another.
+                if !p.is_ready() {
+                    return false;
+                }
+            } else {
+                rest = rest.saturating_add(p);
+            }
+        }
+        rest.is_ready()
+    }
+
+    /// Update (or create) the entry for a specific ID, keeping the sums in
+    /// sync.
+    ///
+    /// All changes to the entries should go through here, so that the
+    /// sums stay correct. Arithmetic saturates instead of overflowing.
+    fn update_entry(
+        &mut self,
+        id: ProgressEntryId,
+        f: impl FnOnce(&mut (Progress, HiddenProgress)),
+    ) {
+        let entry = self.entries.entry(id).or_default();
+        let old = *entry;
+        f(entry);
+        let new = *entry;
+        self.replace_in_sums(old, new);
+    }
+
+    /// Update the `u64` values of an entry, and store them (scaled down to
+    /// fit) as its visible progress.
+    fn update_wide_entry(
+        &mut self,
+        id: ProgressEntryId,
+        f: impl FnOnce(&mut u64, &mut u64),
+    ) {
+        let (done, total) = self.wide.entry(id).or_default();
+        f(done, total);
+        let scaled = scale_u64_progress(*done, *total);
+        self.update_entry(id, |p| p.0 = scaled);
+    }
+
+    /// Update the sums after an entry was removed from `entries`.
+    fn remove_from_sums(&mut self, old: (Progress, HiddenProgress)) {
+        self.replace_in_sums(old, Default::default());
+    }
+
+    /// Update the sums after an entry in `entries` changed from `old` to
+    /// `new`.
+    fn replace_in_sums(
+        &mut self,
+        (v, h): (Progress, HiddenProgress),
+        new: (Progress, HiddenProgress),
+    ) {
+        // Once a sum has saturated, we no longer know how much to subtract
+        // from it, so it must be recomputed from the entries.
+        let (sv, sh) = self.sum_entries;
+        if [sv.done, sv.total, sh.done, sh.total].contains(&u32::MAX) {
+            self.sum_entries = self.entries_sum();
+            return;
+        }
+        debug_assert!(
+            self.sum_entries.0.done >= v.done
+                && self.sum_entries.0.total >= v.total
+                && self.sum_entries.1.done >= h.done
+                && self.sum_entries.1.total >= h.total,
+            "iyes_progress: sum of entries out of sync",
+        );
+        self.sum_entries.0 = self.sum_entries.0.saturating_sub(v);
+        self.sum_entries.1 = self.sum_entries.1.saturating_sub(h);
+        self.sum_entries.0 = self.sum_entries.0.saturating_add(new.0);
+        self.sum_entries.1 = self.sum_entries.1.saturating_add(new.1);
+    }
+
+    fn entries_sum(&self) -> (Progress, HiddenProgress) {
+        let mut sum = (Progress::default(), HiddenProgress::default());
+        for (v, h) in self.entries.values() {
+            sum.0 = sum.0.saturating_add(*v);
+            sum.1 = sum.1.saturating_add(*h);
+        }
+        sum
+    }
+
+    /// Check that the sums match the entries, returning a description of
+    /// the problem if not.
+    fn validate(&self) -> Result<(), String> {
+        let sum = self.entries_sum();
+        if sum != self.sum_entries {
+            return Err(format!(
+                "sum of entries is {:?}, but the stored sum is {:?}",
+                sum, self.sum_entries,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Find the entries that are ready for the first time, and remember
+    /// them, so that they are only returned once.
+    fn take_newly_complete(
+        &mut self,
+    ) -> Vec<(ProgressEntryId, Option<String>)> {
+        let newly: Vec<_> = self
+            .entries
+            .keys()
+            .filter(|id| {
+                !self.completed.contains(*id) && self.is_id_ready(**id)
+            })
+            .copied()
+            .collect();
+        newly
+            .into_iter()
+            .map(|id| {
+                self.completed.insert(id);
+                (id, self.labels.get(&id).cloned())
+            })
+            .collect()
+    }
+
+    fn completion_state(&self) -> CompletionState {
+        if !self.is_ready() {
+            CompletionState::InProgress
+        } else if self.raw_combined_progress().total == 0 {
+            CompletionState::Empty
+        } else {
+            CompletionState::Complete
+        }
+    }
+
+    fn is_entry_blocked(&self, id: ProgressEntryId) -> bool {
+        self.dependencies
+            .get(&id)
+            .is_some_and(|deps| deps.iter().any(|dep| !self.is_id_ready(*dep)))
+    }
+
+    fn is_id_ready(&self, id: ProgressEntryId) -> bool {
+        if self.errors.contains_key(&id) {
+            return self.error_policy == ErrorPolicy::CountAsDone;
+        }
+        let Some(p) = self.combined_progress(id) else {
+            return false;
+        };
+        match self.predicates.get(&id) {
+            Some(predicate) => predicate(p),
+            None => p.is_ready(),
+        }
+    }
 }
 
-impl<S: FreelyMutableState> ProgressTracker<S> {
+impl ProgressStore {
     /// Clear all stored progress values.
-    pub fn clear(&mut self) {
-        self.inner = Default::default();
+    ///
+    /// The [`ErrorPolicy`] and [`DisplayMode`] are preserved.
+    ///
+    /// Entry IDs remain valid after clearing, and an entry is re-created
+    /// the next time progress is reported for its ID. This means that the
+    /// following keep working after a clear (for example, when resetting
+    /// your game world), without re-adding your systems:
+    ///
+    ///  - Systems returning progress (they report again the next time they
+    ///    run, even with `track_progress_and_stop` or `track_progress_every`).
+    ///  - [`ProgressEntity`] components (they are summed up every frame).
+    ///  - Asset tracking (the [`AssetsLoading`] resource is checked every
+    ///    frame).
+    ///  - [`ProgressEntry`], but only once your system writes to it again.
+    ///    Values added with `add_*` methods start again from zero.
+    ///
+    /// These do not survive a clear:
+    ///
+    ///  - [`ProgressSender`]s (including those used by `spawn_tracked` and
+    ///    `track_future`) are disconnected (their updates are ignored) and
+    ///    cancelled (see [`cancel_all`](Self::cancel_all)). Create new ones
+    ///    if the work should continue.
+    ///  - Everything configured for specific IDs: weights, labels, statuses,
+    ///    errors, metadata, dependencies, and readiness predicates. Only the
+    ///    IDs returned by [`entry_for_key`](Self::entry_for_key) are kept.
+    ///  - Reservations made by [`ProgressPlugin::with_reservation`] (they
+    ///    are only re-applied by [`clear_global_progress`]).
+    ///
+    /// This only needs `&self`, so it can be called from systems that run in
+    /// parallel with others that use the tracker (see also
+    /// [`ProgressEntry::clear_all`]). Those systems see the tracker either
+    /// before or after the clear, never in between. Do not call this from
+    /// inside a closure given to another method of the tracker (like
+    /// [`foreach_entry`](Self::foreach_entry) or [`read`](Self::read)),
+    /// because the lock is not re-entrant, so it would deadlock.
+    ///
+    /// Unlike [`clear_global_progress`], this does not send a
+    /// [`ProgressCleared<S>`] event, and does not re-apply reservations.
+    pub fn clear(&self) {
+        let old = {
+            let mut inner = self.inner.lock();
+            let old = std::mem::take(&mut *inner);
+            inner.error_policy = old.error_policy;
+            inner.display_mode = old.display_mode;
+            old
+        };
+        // Drop the old data (which could include user metadata) only
+        // after the lock is released.
+        drop(old);
         #[cfg(feature = "async")]
         {
-            self.chan = None;
+            *self.chan.lock() = None;
+            self.cancel_all();
+        }
+    }
+
+    /// Make the next [`clear_global_progress`] do nothing.
+    ///
+    /// Use this to keep the current progress when (re-)entering a
+    /// progress-tracked state, for example to resume a partially complete
+    /// load, without reconfiguring the [`ProgressPlugin`]. Only one clear is
+    /// skipped; the flag resets itself once it has been used. Calling
+    /// [`clear`](Self::clear) directly is not affected.
+    ///
+    /// If the plugin is configured to clear both on exit and on enter (see
+    /// [`ProgressPlugin::auto_clear`]), only the clear on exit is skipped.
+    pub fn skip_next_clear(&self) {
+        self.skip_next_clear.store(true, Ordering::Relaxed);
+    }
+
+    /// Consume the flag set by [`skip_next_clear`](Self::skip_next_clear).
+    pub(crate) fn take_skip_next_clear(&self) -> bool {
+        self.skip_next_clear.swap(false, Ordering::Relaxed)
+    }
+
+    /// Create a new unique ID, from a counter that belongs to this tracker.
+    ///
+    /// Unlike [`ProgressEntryId::new()`], which uses a process-wide counter,
+    /// IDs created this way are numbered starting from zero for each
+    /// tracker, so they do not depend on what other trackers (or other
+    /// tests running in the same process) have done. The IDs never collide
+    /// with IDs created by [`ProgressEntryId::new()`].
+    ///
+    /// The counter is not reset when the tracker is cleared, so previously
+    /// created IDs never refer to unrelated new entries.
+    pub fn new_id(&self) -> ProgressEntryId {
+        let next_id = self.next_local_id.fetch_add(1, Ordering::Relaxed);
+        ProgressEntryId(LOCAL_ID_BIT | next_id)
+    }
+
+    /// Get the ID for a stable, caller-supplied key.
+    ///
+    /// The first time a key is used, a new ID is allocated for it (like
+    /// [`new_id`](Self::new_id)). After that, the same key always returns
+    /// the same ID from this tracker. The mapping is not reset when the
+    /// tracker is cleared.
+    ///
+    /// The IDs themselves are not stable between runs of your app, but the
+    /// keys are. Use them to persist progress (for example, to resume after
+    /// a restart): save the values under their keys, and on the next run,
+    /// restore them into the IDs returned by this method.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # let tracker = ProgressTracker::<MyStates>::default();
+    /// # struct Saved {
+    /// #     done: u32,
+    /// #     total: u32,
+    /// # }
+    /// # let saved = Saved { done: 1, total: 2 };
+    /// let id = tracker.entry_for_key("download/textures");
+    /// tracker.set_progress(id, saved.done, saved.total);
+    /// ```
+    pub fn entry_for_key(&self, key: &str) -> ProgressEntryId {
+        let mut keys = self.keys.lock();
+        if let Some(id) = keys.get(key) {
+            return *id;
         }
+        let id = self.new_id();
+        keys.insert(key.to_owned(), id);
+        id
     }
 
     /// Create an entry for a background task/thread.
     ///
     /// Returns a [`ProgressSender`], which is the "handle" that
     /// can be used to update the progress stored for the new entry ID.
+    ///
+    /// The channel used to deliver the updates is only created when this
+    /// is first called, so there is no overhead if you never use it.
     #[cfg(feature = "async")]
     pub fn new_async_entry(&mut self) -> ProgressSender {
-        if let Some((tx, _)) = &self.chan {
-            ProgressSender {
-                id: ProgressEntryId::new(),
-                sender: tx.clone(),
-            }
-        } else {
-            let chan = crossbeam_channel::unbounded();
-            let r = ProgressSender {
-                id: ProgressEntryId::new(),
-                sender: chan.0.clone(),
-            };
-            self.chan = Some(chan);
-            r
+        let cancelled = self.cancelled.get_mut().clone();
+        let (tx, _) = self
+            .chan
+            .get_mut()
+            .get_or_insert_with(crossbeam_channel::unbounded);
+        ProgressSender {
+            id: ProgressEntryId::new(),
+            sender: tx.clone(),
+            cancelled,
+        }
+    }
+
+    /// Signal all background work to stop.
+    ///
+    /// All [`ProgressSender`]s created so far will report
+    /// [`is_cancelled`](ProgressSender::is_cancelled) as `true`. It is up to
+    /// your background work to check that and stop early. Senders created
+    /// afterwards are not affected.
+    ///
+    /// This is done automatically when exiting a progress-tracked state,
+    /// and when the tracker is [cleared](Self::clear).
+    #[cfg(feature = "async")]
+    pub fn cancel_all(&self) {
+        let mut cancelled = self.cancelled.lock();
+        cancelled.store(true, Ordering::Relaxed);
+        *cancelled = Default::default();
+    }
+
+    /// Get a future that resolves when all progress is complete.
+    ///
+    /// This is useful for tools and test harnesses that drive the app from
+    /// async code: keep updating the app and `.await` this, instead of
+    /// checking [`is_ready`](Self::is_ready) in a loop.
+    ///
+    /// The future resolves the next time progress is checked (in the same
+    /// schedule as the state transition, `Last` by default) while in a
+    /// progress-tracked state, and [`is_ready`](Self::is_ready) is `true`.
+    /// Clearing the tracker does not affect it: if you create the future
+    /// after progress has already completed and the state has been exited,
+    /// it resolves when the next tracked state completes.
+    ///
+    /// The future also resolves if the tracker is removed from the world.
+    #[cfg(feature = "async")]
+    pub fn completion_future(&self) -> impl Future<Output = ()> + Send {
+        let (tx, rx) = futures_channel::oneshot::channel();
+        self.completion_waiters.lock().push(tx);
+        async move {
+            rx.await.ok();
         }
     }
 
+    #[cfg(feature = "async")]
+    pub(crate) fn has_completion_waiters(&self) -> bool {
+        !self.completion_waiters.lock().is_empty()
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn notify_completion_waiters(&self) {
+        for tx in self.completion_waiters.lock().drain(..) {
+            tx.send(()).ok();
+        }
+    }
+
+    /// Run some work on a new OS thread, tracking it as a new entry.
+    ///
+    /// This is a shortcut for the common pattern of creating an entry with
+    /// [`new_async_entry`](Self::new_async_entry), setting its total, and
+    /// moving the [`ProgressSender`] into a background thread. The total is
+    /// set immediately, and the entry is marked as fully done when `f`
+    /// returns. `f` can use the [`ProgressSender`] it is given to report
+    /// intermediate progress, and to check if the work
+    /// [has been cancelled](ProgressSender::is_cancelled). If `f` panics,
+    /// the entry is never completed.
+    ///
+    /// Returns the [`ProgressEntryId`] of the new entry, so you can refer
+    /// to it later (for example, from your UI).
+    ///
+    /// Remember that the values are lost if the tracker is cleared
+    /// afterwards. If you call this from `OnEnter`, order your system
+    /// `.after(clear_global_progress::<S>)`.
+    ///
+    /// Not available on `wasm32`, because there are no OS threads. Use
+    /// [`new_async_entry`](Self::new_async_entry) with an async task instead.
+    #[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+    pub fn spawn_tracked<F>(&mut self, total: u32, f: F) -> ProgressEntryId
+    where
+        F: FnOnce(&ProgressSender) + Send + 'static,
+    {
+        let sender = self.new_async_entry();
+        let id = sender.id();
+        self.set_total(id, total);
+        std::thread::spawn(move || {
+            f(&sender);
+            sender.set_done(total);
+        });
+        id
+    }
+
+    /// Run a future on Bevy's `AsyncComputeTaskPool`, tracking it as a new
+    /// entry.
+    ///
+    /// This is the async counterpart of
+    /// [`spawn_tracked`](Self::spawn_tracked). `f` is given the
+    /// [`ProgressSender`] for the new entry (ignore it if you do not need
+    /// to report intermediate progress), and returns the future to run. The
+    /// total is set immediately, and the entry is marked as fully done when
+    /// the future completes.
+    ///
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # let mut tracker = ProgressTracker::<MyStates>::default();
+    /// # let n_chunks = 10;
+    /// # let mut data = Vec::new();
+    /// # async fn download_chunk(_i: u32) -> Vec<u8> {
+    /// #     Vec::new()
+    /// # }
+    /// let id = tracker.track_future(n_chunks, |sender| async move {
+    ///     for i in 0..n_chunks {
+    ///         if sender.is_cancelled() {
+    ///             return;
+    ///         }
+    ///         data.extend(download_chunk(i).await);
+    ///         sender.add_done(1);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Like with [`new_async_entry`](Self::new_async_entry), the future keeps
+    /// running if the work is cancelled (for example, when the state is
+    /// exited), so check [`is_cancelled`](ProgressSender::is_cancelled) if
+    /// it takes a long time. Remember that the values are lost if the
+    /// tracker is cleared afterwards. If you call this from `OnEnter`, order
+    /// your system `.after(clear_global_progress::<S>)`.
+    ///
+    /// Panics if the `AsyncComputeTaskPool` has not been initialized (it is
+    /// set up by Bevy's `TaskPoolPlugin`, which is part of both
+    /// `DefaultPlugins` and `MinimalPlugins`).
+    #[cfg(feature = "async")]
+    pub fn track_future<F, Fut>(&mut self, total: u32, f: F) -> ProgressEntryId
+    where
+        F: FnOnce(ProgressSender) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let sender = self.new_async_entry();
+        let id = sender.id();
+        self.set_total(id, total);
+        let done = sender.clone();
+        let fut = f(sender);
+        bevy_tasks::AsyncComputeTaskPool::get()
+            .spawn(async move {
+                fut.await;
+                done.set_done(total);
+            })
+            .detach();
+        id
+    }
+
     /// Call a closure on each entry stored in the tracker.
     ///
     /// This allows you to inspect or mutate anything stored in the tracker,
     /// which can be useful for debugging or for advanced use cases.
+    ///
+    /// Entries are visited in a stable order, sorted by [`ProgressEntryId`]
+    /// (which means in the order the IDs were created).
     pub fn foreach_entry(
         &self,
         mut f: impl FnMut(ProgressEntryId, &mut Progress, &mut HiddenProgress),
     ) {
-        let mut inner = self.inner.lock();
+        let inner = &mut *self.inner.lock();
         for (k, v) in inner.entries.iter_mut() {
             f(*k, &mut v.0, &mut v.1);
         }
+        // The values may have been changed, so the sums must be recomputed.
+        inner.sum_entries = inner.entries_sum();
+    }
+
+    /// Access all the progress data under a single lock.
+    ///
+    /// The closure is given a read-only [`GlobalProgressView`], which you can
+    /// use to read as many values as you like, without locking the tracker
+    /// separately for each one. All the values you read will be consistent
+    /// with each other.
+    ///
+    /// The tracker is locked while the closure runs. Do not try to access
+    /// the tracker from inside it, or you will deadlock.
+    pub fn read<R>(&self, f: impl FnOnce(&GlobalProgressView) -> R) -> R {
+        let inner = self.inner.lock();
+        f(&GlobalProgressView { inner: &inner })
     }
 
     /// Check if there is any progress data stored for a given ID.
@@ -130,20 +911,468 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
     /// Check if all progress is complete.
     ///
     /// This accounts for both visible progress and hidden progress.
+    ///
+    /// Entries that have a custom predicate (see
+    /// [`set_ready_predicate`](Self::set_ready_predicate)) must each satisfy
+    /// their predicate. All other progress is checked as a sum.
+    ///
+    /// Entries that have an error (see [`set_error`](Self::set_error)) are
+    /// handled according to the [`ErrorPolicy`].
     pub fn is_ready(&self) -> bool {
-        self.get_global_combined_progress().is_ready()
+        self.inner.lock().is_ready()
+    }
+
+    /// Check if there is no work, some work in progress, or all work is
+    /// complete.
+    ///
+    /// Unlike [`is_ready`](Self::is_ready), this distinguishes between
+    /// having no work at all ([`CompletionState::Empty`]) and having
+    /// completed all the work ([`CompletionState::Complete`]).
+    pub fn completion_state(&self) -> CompletionState {
+        self.inner.lock().completion_state()
+    }
+
+    /// Check if all progress is complete, ignoring a specific ID.
+    ///
+    /// Like [`is_ready`](Self::is_ready), but as if the entry for the given
+    /// ID did not exist. This is useful for exit gates (see
+    /// [`ProgressPlugin::with_exit_gate`]).
+    pub fn is_ready_except(&self, id: ProgressEntryId) -> bool {
+        self.inner.lock().is_ready_except(Some(id))
     }
 
     /// Check if the progress for a specific ID is complete.
     ///
     /// This accounts for both visible progress and hidden progress.
+    ///
+    /// If the ID has a custom predicate (see
+    /// [`set_ready_predicate`](Self::set_ready_predicate)), it is used
+    /// instead of the default `done >= total` check.
+    ///
+    /// If the ID has an error (see [`set_error`](Self::set_error)), it is
+    /// handled according to the [`ErrorPolicy`].
     pub fn is_id_ready(&self, id: ProgressEntryId) -> bool {
+        self.inner.lock().is_id_ready(id)
+    }
+
+    /// Customize what "ready" means for a specific ID.
+    ///
+    /// By default, an entry is ready when `done >= total`. With a custom
+    /// predicate, the entry is ready when the predicate returns `true`. The
+    /// predicate is given the combined (visible+hidden) progress of the
+    /// entry. This affects both [`is_id_ready`](Self::is_id_ready) and the
+    /// global [`is_ready`](Self::is_ready).
+    ///
+    /// Be careful: if your predicate never returns `true`, the progress will
+    /// never be ready and no state transition will happen.
+    ///
+    /// Predicates are cleared together with everything else in the tracker.
+    pub fn set_ready_predicate(
+        &self,
+        id: ProgressEntryId,
+        predicate: impl Fn(Progress) -> bool + Send + Sync + 'static,
+    ) {
+        self.inner.lock().predicates.insert(id, Box::new(predicate));
+    }
+
+    /// Remove a custom readiness predicate, restoring the default behavior
+    /// for a specific ID.
+    pub fn remove_ready_predicate(&self, id: ProgressEntryId) {
+        self.inner.lock().predicates.remove(&id);
+    }
+
+    /// Make a specific ID count more (or less) towards the global progress.
+    ///
+    /// By default, every entry has a weight of `1.0`, and the global
+    /// progress is simply the sum of all the entries. An entry with a weight
+    /// of `2.0` counts as if its `done` and `total` were twice as large. Use
+    /// this if some of your work is much more significant than its
+    /// `done`/`total` would suggest (like loading a huge map vs. a dummy
+    /// system). The weighted sums are rounded to the nearest unit.
+    ///
+    /// Weighted entries are checked individually for readiness: everything
+    /// is only ready once every weighted entry is complete on its own, not
+    /// just when the weighted sum reaches 100%.
+    ///
+    /// Negative and NaN weights are treated as `0.0` (the entry does not
+    /// move the progress bar, but must still complete). Infinite weights
+    /// are clamped to `f32::MAX`. Weights are cleared together with
+    /// everything else in the tracker.
+    pub fn set_weight(&self, id: ProgressEntryId, weight: f32) {
+        let weight = if weight.is_nan() {
+            0.0
+        } else {
+            weight.clamp(0.0, f32::MAX)
+        };
+        let mut inner = self.inner.lock();
+        if weight == 1.0 {
+            inner.weights.remove(&id);
+        } else {
+            inner.weights.insert(id, weight);
+        }
+    }
+
+    /// Get the weight of a specific ID (see
+    /// [`set_weight`](Self::set_weight)).
+    ///
+    /// Returns `1.0` if no weight has been set.
+    pub fn get_weight(&self, id: ProgressEntryId) -> f32 {
+        self.inner.lock().weights.get(&id).copied().unwrap_or(1.0)
+    }
+
+    /// Declare that the work of a specific ID should not start until the
+    /// work of another ID is ready.
+    ///
+    /// This does not affect how progress is tracked or when anything runs.
+    /// It only lets you ask if the entry is waiting for others (see
+    /// [`is_entry_blocked`](Self::is_entry_blocked)). Use that to gate the
+    /// systems doing the work (with a run condition), or to show entries
+    /// that have not started yet differently in your UI:
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # let mut app = App::new();
+    /// # let tracker = ProgressTracker::<MyStates>::default();
+    /// # let build_navmesh = ProgressEntryId::new();
+    /// # let load_level = ProgressEntryId::new();
+    /// # fn build_navmesh_system() {}
+    /// tracker.add_dependency(build_navmesh, load_level);
+    ///
+    /// app.add_systems(
+    ///     Update,
+    ///     build_navmesh_system.run_if(
+    ///         move |t: Res<ProgressTracker<MyStates>>| {
+    ///             !t.is_entry_blocked(build_navmesh)
+    ///         },
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// Adding the same dependency again has no effect. Dependencies are
+    /// cleared together with everything else in the tracker.
+    pub fn add_dependency(
+        &self,
+        id: ProgressEntryId,
+        depends_on: ProgressEntryId,
+    ) {
+        let deps = &mut self.inner.lock().dependencies;
+        let deps = deps.entry(id).or_default();
+        if !deps.contains(&depends_on) {
+            deps.push(depends_on);
+        }
+    }
+
+    /// Remove all dependencies of a specific ID.
+    pub fn clear_dependencies(&self, id: ProgressEntryId) {
+        self.inner.lock().dependencies.remove(&id);
+    }
+
+    /// Check if a specific ID is waiting for any of its dependencies to be
+    /// ready.
+    ///
+    /// See [`add_dependency`](Self::add_dependency). A dependency that has
+    /// no entry in the tracker yet is not ready.
+    pub fn is_entry_blocked(&self, id: ProgressEntryId) -> bool {
+        self.inner.lock().is_entry_blocked(id)
+    }
+
+    /// Remove the entry for a specific ID, with everything associated with
+    /// it (status, label, metadata, etc.).
+    ///
+    /// Its progress no longer counts towards the global progress. Use this
+    /// if some work is no longer needed (for example, a background task
+    /// that was cancelled), so that it does not hold up everything else.
+    /// Removing an ID that has no entry does nothing.
+    ///
+    /// If the ID is reported again afterwards, a new entry is created. Other
+    /// entries that depend on it (see [`add_dependency`](Self::add_dependency))
+    /// remain blocked, as if it had not been reported yet.
+    pub fn remove_entry(&self, id: ProgressEntryId) {
+        let inner = &mut *self.inner.lock();
+        if let Some(entry) = inner.entries.remove(&id) {
+            inner.remove_from_sums(entry);
+        }
+        inner.metadata.remove(&id);
+        inner.predicates.remove(&id);
+        inner.errors.remove(&id);
+        inner.statuses.remove(&id);
+        inner.labels.remove(&id);
+        inner.eta_samples.remove(&id);
+        inner.wide.remove(&id);
+        inner.dependencies.remove(&id);
+        inner.weights.remove(&id);
+        inner.completed.remove(&id);
+    }
+
+    /// Mark a specific ID as having failed, with an error message.
+    ///
+    /// Use this to let your UI know that something went wrong (check
+    /// [`has_errors`](Self::has_errors)), instead of waiting forever for
+    /// work that will never complete.
+    ///
+    /// How errored entries affect readiness is determined by the
+    /// [`ErrorPolicy`] (see [`set_error_policy`](Self::set_error_policy)).
+    ///
+    /// Errors are cleared together with everything else in the tracker.
+    pub fn set_error(&self, id: ProgressEntryId, message: impl Into<String>) {
+        self.inner.lock().errors.insert(id, message.into());
+    }
+
+    /// Remove the error from a specific ID (for example, if you retried
+    /// the work that failed).
+    pub fn clear_error(&self, id: ProgressEntryId) {
+        self.inner.lock().errors.remove(&id);
+    }
+
+    /// Get the error message of a specific ID, if it has an error.
+    pub fn get_error(&self, id: ProgressEntryId) -> Option<String> {
+        self.inner.lock().errors.get(&id).cloned()
+    }
+
+    /// Check if any entry has an error.
+    pub fn has_errors(&self) -> bool {
+        !self.inner.lock().errors.is_empty()
+    }
+
+    /// Call a closure on each entry that has an error.
+    pub fn foreach_error(&self, mut f: impl FnMut(ProgressEntryId, &str)) {
+        let inner = self.inner.lock();
+        for (id, message) in inner.errors.iter() {
+            f(*id, message);
+        }
+    }
+
+    /// Get the current [`ErrorPolicy`].
+    pub fn error_policy(&self) -> ErrorPolicy {
+        self.inner.lock().error_policy
+    }
+
+    /// Configure how entries with errors affect readiness.
+    ///
+    /// The policy is not reset when the tracker is cleared.
+    ///
+    /// Default: [`ErrorPolicy::Block`]
+    pub fn set_error_policy(&self, policy: ErrorPolicy) {
+        self.inner.lock().error_policy = policy;
+    }
+
+    /// Attach arbitrary user data to a specific ID.
+    ///
+    /// This can be used to map entries back to whatever they represent
+    /// in your game, for diagnostics or advanced progress UIs. Any previous
+    /// value (of any type) is replaced.
+    ///
+    /// Metadata is cleared together with everything else in the tracker.
+    pub fn set_metadata<T: Any + Send + Sync>(
+        &self,
+        id: ProgressEntryId,
+        value: T,
+    ) {
+        self.inner.lock().metadata.insert(id, Box::new(value));
+    }
+
+    /// Get a copy of the user data attached to a specific ID.
+    ///
+    /// Returns `None` if there is no data, or if it is not of type `T`.
+    pub fn get_metadata<T: Any + Clone>(
+        &self,
+        id: ProgressEntryId,
+    ) -> Option<T> {
+        self.with_metadata(id, T::clone)
+    }
+
+    /// Access the user data attached to a specific ID, without copying it.
+    ///
+    /// Returns `None` if there is no data, or if it is not of type `T`.
+    ///
+    /// The tracker is locked while the closure runs. Do not try to access
+    /// the tracker from inside it, or you will deadlock.
+    pub fn with_metadata<T: Any, R>(
+        &self,
+        id: ProgressEntryId,
+        f: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
         let inner = self.inner.lock();
         inner
-            .entries
+            .metadata
             .get(&id)
-            .map(|x| (x.0 + x.1 .0).is_ready())
-            .unwrap_or_default()
+            .and_then(|m| m.downcast_ref::<T>())
+            .map(f)
+    }
+
+    /// Remove the user data attached to a specific ID.
+    pub fn remove_metadata(&self, id: ProgressEntryId) {
+        self.inner.lock().metadata.remove(&id);
+    }
+
+    /// Set a status message for a specific ID.
+    ///
+    /// This is useful for work where the amount of progress does not say
+    /// much, like network handshakes. Your UI can display the message (for
+    /// example, "Connecting…", "Authenticating…").
+    ///
+    /// Statuses are cleared together with everything else in the tracker.
+    pub fn set_status(&self, id: ProgressEntryId, status: impl Into<String>) {
+        self.inner.lock().statuses.insert(id, status.into());
+    }
+
+    /// Remove the status message of a specific ID.
+    pub fn clear_status(&self, id: ProgressEntryId) {
+        self.inner.lock().statuses.remove(&id);
+    }
+
+    /// Get the status message of a specific ID, if it has one.
+    pub fn get_status(&self, id: ProgressEntryId) -> Option<String> {
+        self.inner.lock().statuses.get(&id).cloned()
+    }
+
+    /// Give a human-readable name to a specific ID.
+    ///
+    /// Labels are used for debugging output and can be displayed in your UI
+    /// (for example, to list all the tasks, see
+    /// [`labeled_fractions`](Self::labeled_fractions)).
+    ///
+    /// Labels are cleared together with everything else in the tracker.
+    pub fn set_label(&self, id: ProgressEntryId, label: impl Into<String>) {
+        self.inner.lock().labels.insert(id, label.into());
+    }
+
+    /// Remove the label of a specific ID.
+    pub fn clear_label(&self, id: ProgressEntryId) {
+        self.inner.lock().labels.remove(&id);
+    }
+
+    /// Get the label of a specific ID, if it has one.
+    pub fn get_label(&self, id: ProgressEntryId) -> Option<String> {
+        self.inner.lock().labels.get(&id).cloned()
+    }
+
+    /// Get the label and completed fraction of every entry.
+    ///
+    /// This is everything you need to display a list of tasks, each with
+    /// its own progress bar. Entries are sorted by [`ProgressEntryId`].
+    /// Entries without a label are included, with `None`.
+    ///
+    /// The fractions are computed like [`Progress::fraction`], so entries
+    /// with nothing to do (a total of zero) count as complete, and the
+    /// fraction is not clamped if more work is done than expected. Which
+    /// progress they include depends on the [`DisplayMode`].
+    pub fn labeled_fractions(&self) -> Vec<(Option<String>, f32)> {
+        let inner = self.inner.lock();
+        inner
+            .entries
+            .iter()
+            .map(|(id, (v, h))| {
+                let p = match inner.display_mode {
+                    DisplayMode::VisibleOnly => *v,
+                    DisplayMode::Combined => v.saturating_add(h.0),
+                };
+                (inner.labels.get(id).cloned(), p.fraction())
+            })
+            .collect()
+    }
+
+    /// Provide a value to be passed on to the next state.
+    ///
+    /// When all progress is complete and the state transition is queued,
+    /// `value` will be inserted as a resource, so that the systems of the
+    /// next state (including `OnEnter`) can access it. Use this to hand off
+    /// the results of your loading (for example, a generated world seed).
+    ///
+    /// You can provide multiple values of different types. Setting a value
+    /// of the same type again replaces the previous one, when inserted.
+    ///
+    /// Payloads are cleared together with everything else in the tracker.
+    /// They are only used by the automatic transition (see
+    /// [`with_state_transition`](ProgressPlugin::with_state_transition)).
+    pub fn set_transition_payload<T: Resource>(&self, value: T) {
+        self.inner.lock().payloads.push(Box::new(move |commands| {
+            commands.insert_resource(value);
+        }));
+    }
+
+    pub(crate) fn insert_transition_payloads(&self, commands: &mut Commands) {
+        let payloads = std::mem::take(&mut self.inner.lock().payloads);
+        for payload in payloads {
+            payload(commands);
+        }
+    }
+
+    /// Start estimating the remaining time for a specific ID.
+    ///
+    /// This records a history of the entry's (combined) progress over time,
+    /// so it costs some extra memory and work every frame. That is why it
+    /// must be enabled explicitly, only for the entries you care about.
+    /// Once enabled, use [`entry_eta`](Self::entry_eta) to get the estimate.
+    ///
+    /// This is cleared together with everything else in the tracker.
+    pub fn enable_eta(&self, id: ProgressEntryId) {
+        self.inner.lock().eta_samples.entry(id).or_default();
+    }
+
+    /// Stop estimating the remaining time for a specific ID.
+    pub fn disable_eta(&self, id: ProgressEntryId) {
+        self.inner.lock().eta_samples.remove(&id);
+    }
+
+    /// Get the estimated time until a specific ID is complete.
+    ///
+    /// The estimate is based on how fast the entry's (combined) progress
+    /// has been advancing over the last few seconds. Returns `None` if
+    /// estimation was not enabled for the ID (see
+    /// [`enable_eta`](Self::enable_eta)), if there is not enough data yet,
+    /// or if there has been no recent progress. Returns zero if the entry
+    /// is already complete.
+    ///
+    /// Time is measured in virtual time (`Time<Virtual>`), so the estimate
+    /// accounts for pausing and speed scaling: time spent paused does not
+    /// count as time without progress.
+    pub fn entry_eta(&self, id: ProgressEntryId) -> Option<Duration> {
+        let inner = self.inner.lock();
+        let samples = inner.eta_samples.get(&id)?;
+        let &(t_last, last) = samples.back()?;
+        if last.is_ready() {
+            return Some(Duration::ZERO);
+        }
+        let &(t_first, first) = samples.front()?;
+        let elapsed = t_last.saturating_sub(t_first);
+        let advanced = last.done.saturating_sub(first.done);
+        if elapsed.is_zero() || advanced == 0 {
+            return None;
+        }
+        let remaining = last.total - last.done;
+        Some(elapsed.mul_f64(remaining as f64 / advanced as f64))
+    }
+
+    fn sample_eta(&self, now: Duration) {
+        let inner = &mut *self.inner.lock();
+        for (id, samples) in inner.eta_samples.iter_mut() {
+            let p = inner
+                .entries
+                .get(id)
+                .map(|x| x.0.saturating_add(x.1 .0))
+                .unwrap_or_default();
+            // If the progress went backwards (the entry was reset),
+            // the old samples are meaningless.
+            if samples.back().is_some_and(|(_, last)| p.done < last.done) {
+                samples.clear();
+            }
+            samples.push_back((now, p));
+            while samples.len() > 2
+                && samples
+                    .get(1)
+                    .is_some_and(|(t, _)| now.saturating_sub(*t) >= ETA_WINDOW)
+            {
+                samples.pop_front();
+            }
+        }
     }
 
     pub(crate) fn set_sum_entities(&self, v: Progress, h: HiddenProgress) {
@@ -157,23 +1386,106 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
     /// This is what you should use to display a progress bar or
     /// other user-facing indicator.
     pub fn get_global_progress(&self) -> Progress {
-        let inner = self.inner.lock();
-        inner.sum_entries.0 + inner.sum_entities.0
+        self.inner.lock().global_progress()
     }
 
     /// Get the overall hidden progress.
     pub fn get_global_hidden_progress(&self) -> HiddenProgress {
+        self.inner.lock().global_hidden_progress()
+    }
+
+    /// Get the overall visible progress as a fraction, for display as a
+    /// progress bar.
+    ///
+    /// This is [`Progress::fraction`] of
+    /// [`get_global_progress`](Self::get_global_progress): never `NaN`,
+    /// `1.0` if there is no work, and not clamped (greater than `1.0` if
+    /// more work was done than expected).
+    pub fn global_fraction(&self) -> f32 {
+        self.get_global_progress().fraction()
+    }
+
+    /// Get the visible progress for a specific ID as a fraction.
+    ///
+    /// This is [`Progress::fraction`] of
+    /// [`get_progress`](Self::get_progress), like
+    /// [`global_fraction`](Self::global_fraction). It returns `1.0` if the
+    /// entry has no work (or does not exist).
+    pub fn entry_fraction(&self, id: ProgressEntryId) -> f32 {
+        self.get_progress(id).fraction()
+    }
+
+    /// Get the overall visible progress as a percentage (`0..=100`), for
+    /// display as text.
+    ///
+    /// Like [`Progress::percent`], this is capped at `99` until the work is
+    /// ready, so it never shows `100` too early. Here, "ready" means that
+    /// everything [`is_ready`](Self::is_ready): the result stays at `99`
+    /// while hidden work remains, even if the visible progress is complete.
+    pub fn global_percent(&self) -> u8 {
+        let inner = self.inner.lock();
+        cap_percent(inner.global_progress().percent(), inner.is_ready())
+    }
+
+    /// Get the visible progress for a specific ID as a percentage
+    /// (`0..=100`), for display as text.
+    ///
+    /// Like [`Progress::percent`], this is capped at `99` until the work is
+    /// ready. Here, "ready" means that the entry
+    /// [`is_id_ready`](Self::is_id_ready): the result stays at `99` while
+    /// its hidden work remains, or while it has an error (unless errors
+    /// count as done, see [`ErrorPolicy`]).
+    pub fn entry_percent(&self, id: ProgressEntryId) -> u8 {
         let inner = self.inner.lock();
-        inner.sum_entries.1 + inner.sum_entities.1
+        let progress = inner.entries.get(&id).copied().unwrap_or_default().0;
+        cap_percent(progress.percent(), inner.is_id_ready(id))
+    }
+
+    /// Get the overall progress that should be shown to the user.
+    ///
+    /// Depending on the [`DisplayMode`] (see
+    /// [`set_display_mode`](Self::set_display_mode)), this is either the
+    /// visible progress (default), or the combined visible+hidden progress.
+    /// Use this in your UI, so that it can be switched with a single flag.
+    pub fn get_displayed_progress(&self) -> Progress {
+        self.inner.lock().displayed_progress()
+    }
+
+    /// Get the current [`DisplayMode`].
+    pub fn display_mode(&self) -> DisplayMode {
+        self.inner.lock().display_mode
+    }
+
+    /// Configure what [`get_displayed_progress`](Self::get_displayed_progress)
+    /// should include.
+    ///
+    /// The mode is not reset when the tracker is cleared.
+    ///
+    /// Default: [`DisplayMode::VisibleOnly`]
+    pub fn set_display_mode(&self, mode: DisplayMode) {
+        self.inner.lock().display_mode = mode;
     }
 
     /// Get the overall visible+hidden progress.
     ///
     /// This is what you should use to determine if all work is complete.
     pub fn get_global_combined_progress(&self) -> Progress {
-        let inner = self.inner.lock();
-        inner.sum_entries.0 + inner.sum_entries.1 .0 +
-        inner.sum_entities.0 + inner.sum_entities.1 .0
+        self.inner.lock().global_combined_progress()
+    }
+
+    /// Get both the visible and hidden progress stored for a specific ID.
+    ///
+    /// Both values are read under a single lock, so they are consistent
+    /// with each other, even if other systems are updating the entry in
+    /// parallel. Prefer this over calling [`get_done`](Self::get_done) and
+    /// [`get_total`](Self::get_total) separately.
+    ///
+    /// Returns `None` if there is no entry for the given ID.
+    pub fn get_entry(
+        &self,
+        id: ProgressEntryId,
+    ) -> Option<(Progress, HiddenProgress)> {
+        self.inner.lock().entries.get(&id).copied()
     }
 
     /// Get the visible progress stored for a specific ID.
@@ -190,12 +1502,7 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
 
     /// Get the visible+hidden progress stored for a specific ID.
     pub fn get_combined_progress(&self, id: ProgressEntryId) -> Progress {
-        let inner = self.inner.lock();
-        inner
-            .entries
-            .get(&id)
-            .map(|x| x.0 + x.1 .0)
-            .unwrap_or_default()
+        self.inner.lock().combined_progress(id).unwrap_or_default()
     }
 
     /// Get the (visible) expected work item count for a specific ID.
@@ -226,33 +1533,19 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
     ///
     /// Use this when you want to overwrite both the `total` and `done` at once.
     pub fn set_progress(&self, id: ProgressEntryId, done: u32, total: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            if p.0.total < total {
-                let diff = total - p.0.total;
-                inner.sum_entries.0.total += diff;
-            }
-            if p.0.total > total {
-                let diff = p.0.total - total;
-                inner.sum_entries.0.total -= diff;
-            }
-            if p.0.done < done {
-                let diff = done - p.0.done;
-                inner.sum_entries.0.done += diff;
-            }
-            if p.0.done > done {
-                let diff = p.0.done - done;
-                inner.sum_entries.0.done -= diff;
-            }
+        self.inner.lock().update_entry(id, |p| {
             p.0 = Progress { done, total };
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress { done, total }, HiddenProgress::default()),
-            );
-            inner.sum_entries.0.total += total;
-            inner.sum_entries.0.done += done;
-        }
+        });
+    }
+
+    /// Overwrite the stored visible progress for a specific ID, from a
+    /// completed fraction (`0.0..=1.0`).
+    ///
+    /// See [`Progress::from_fraction`].
+    pub fn set_fraction(&self, id: ProgressEntryId, fraction: f32) {
+        self.inner.lock().update_entry(id, |p| {
+            p.0 = Progress::from_fraction(fraction);
+        });
     }
 
     /// Overwrite the stored hidden progress for a specific ID.
@@ -264,121 +1557,75 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
         done: u32,
         total: u32,
     ) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            if p.1.total < total {
-                let diff = total - p.1.total;
-                inner.sum_entries.1.total += diff;
-            }
-            if p.1.total > total {
-                let diff = p.1.total - total;
-                inner.sum_entries.1.total -= diff;
-            }
-            if p.1.done < done {
-                let diff = done - p.1.done;
-                inner.sum_entries.1.done += diff;
-            }
-            if p.1.done > done {
-                let diff = p.1.done - done;
-                inner.sum_entries.1.done -= diff;
-            }
+        self.inner.lock().update_entry(id, |p| {
             p.1 = Progress { done, total }.into();
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress::default(), Progress { done, total }.into()),
-            );
-            inner.sum_entries.1.total += total;
-            inner.sum_entries.1.done += done;
-        }
+        });
+    }
+
+    /// Overwrite both the stored visible and hidden progress for a specific
+    /// ID, at once.
+    ///
+    /// Unlike calling [`set_progress`](Self::set_progress) and
+    /// [`set_hidden_progress`](Self::set_hidden_progress) separately, other
+    /// systems can never observe the entry with only one of them updated.
+    pub fn set_all(
+        &self,
+        id: ProgressEntryId,
+        visible: Progress,
+        hidden: HiddenProgress,
+    ) {
+        self.inner.lock().update_entry(id, |p| {
+            *p = (visible, hidden);
+        });
     }
 
     /// Overwrite the stored (visible) expected work items for a specific ID.
     pub fn set_total(&self, id: ProgressEntryId, total: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            if p.0.total < total {
-                let diff = total - p.0.total;
-                inner.sum_entries.0.total += diff;
-            }
-            if p.0.total > total {
-                let diff = p.0.total - total;
-                inner.sum_entries.0.total -= diff;
-            }
+        self.inner.lock().update_entry(id, |p| {
             p.0.total = total;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress { done: 0, total }, HiddenProgress::default()),
-            );
-            inner.sum_entries.0.total += total;
+        });
+    }
+
+    /// Reserve (visible) expected work items for a specific ID, before the
+    /// actual work starts.
+    ///
+    /// Use this to let the total be known from the start, so that progress
+    /// bars do not start at `0/0` and jump around as more work is
+    /// registered. This creates the entry with `done: 0` and the given
+    /// `total`. If the entry already exists, nothing happens. The code that
+    /// does the actual work can then set the progress as usual.
+    ///
+    /// Reservations are cleared together with everything else in the
+    /// tracker. If you want reservations to be re-applied every time the
+    /// progress is cleared, use [`ProgressPlugin::with_reservation`].
+    pub fn reserve(&self, id: ProgressEntryId, total: u32) {
+        let inner = &mut *self.inner.lock();
+        if !inner.entries.contains_key(&id) {
+            inner.update_entry(id, |p| {
+                p.0.total = total;
+            });
         }
     }
 
     /// Overwrite the stored (visible) completed work items for a specific ID.
     pub fn set_done(&self, id: ProgressEntryId, done: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            if p.0.done < done {
-                let diff = done - p.0.done;
-                inner.sum_entries.0.done += diff;
-            }
-            if p.0.done > done {
-                let diff = p.0.done - done;
-                inner.sum_entries.0.done -= diff;
-            }
+        self.inner.lock().update_entry(id, |p| {
             p.0.done = done;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress { done, total: 0 }, HiddenProgress::default()),
-            );
-            inner.sum_entries.0.done += done;
-        }
+        });
     }
 
     /// Overwrite the stored (hidden) expected work items for a specific ID.
     pub fn set_hidden_total(&self, id: ProgressEntryId, total: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            if p.1.total < total {
-                let diff = total - p.1.total;
-                inner.sum_entries.1.total += diff;
-            }
-            if p.1.total > total {
-                let diff = p.1.total - total;
-                inner.sum_entries.1.total -= diff;
-            }
+        self.inner.lock().update_entry(id, |p| {
             p.1.total = total;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress::default(), Progress { done: 0, total }.into()),
-            );
-            inner.sum_entries.1.total += total;
-        }
+        });
     }
 
     /// Overwrite the stored (hidden) completed work items for a specific ID.
     pub fn set_hidden_done(&self, id: ProgressEntryId, done: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            if p.1.done < done {
-                let diff = done - p.1.done;
-                inner.sum_entries.1.done += diff;
-            }
-            if p.1.done > done {
-                let diff = p.1.done - done;
-                inner.sum_entries.1.done -= diff;
-            }
+        self.inner.lock().update_entry(id, |p| {
             p.1.done = done;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress::default(), Progress { done, total: 0 }.into()),
-            );
-            inner.sum_entries.1.done += done;
-        }
+        });
     }
 
     /// Add more (visible) work items to the previously stored progress for a
@@ -386,48 +1633,25 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
     ///
     /// Use this when you want to add to both the `total` and `done` at once.
     pub fn add_progress(&self, id: ProgressEntryId, done: u32, total: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            p.0.done += done;
-            p.0.total += total;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress { done, total }, HiddenProgress::default()),
-            );
-        }
-        inner.sum_entries.0.total += total;
-        inner.sum_entries.0.done += done;
+        self.inner.lock().update_entry(id, |p| {
+            p.0 = p.0.saturating_add(Progress { done, total });
+        });
     }
 
     /// Add more (visible) expected work items to the previously stored value
     /// for a specific ID.
     pub fn add_total(&self, id: ProgressEntryId, total: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            p.0.total += total;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress { done: 0, total }, HiddenProgress::default()),
-            );
-        }
-        inner.sum_entries.0.total += total;
+        self.inner.lock().update_entry(id, |p| {
+            p.0.total = p.0.total.saturating_add(total);
+        });
     }
 
     /// Add more (visible) completed work items to the previously stored value
     /// for a specific ID.
     pub fn add_done(&self, id: ProgressEntryId, done: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            p.0.done += done;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress { done, total: 0 }, HiddenProgress::default()),
-            );
-        }
-        inner.sum_entries.0.done += done;
+        self.inner.lock().update_entry(id, |p| {
+            p.0.done = p.0.done.saturating_add(done);
+        });
     }
 
     /// Add more (hidden) work items to the previously stored progress for a
@@ -440,48 +1664,192 @@ impl<S: FreelyMutableState> ProgressTracker<S> {
         done: u32,
         total: u32,
     ) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            p.1.done += done;
-            p.1.total += total;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress::default(), Progress { done, total }.into()),
-            );
-        }
-        inner.sum_entries.1.total += total;
-        inner.sum_entries.1.done += done;
+        self.inner.lock().update_entry(id, |p| {
+            p.1 = p.1.saturating_add(Progress { done, total }.into());
+        });
     }
 
     /// Add more (hidden) expected work items to the previously stored value for
     /// a specific ID.
     pub fn add_hidden_total(&self, id: ProgressEntryId, total: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            p.1.total += total;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress::default(), Progress { done: 0, total }.into()),
-            );
-        }
-        inner.sum_entries.1.total += total;
+        self.inner.lock().update_entry(id, |p| {
+            p.1.total = p.1.total.saturating_add(total);
+        });
     }
 
     /// Add more (hidden) completed work items to the previously stored value
     /// for a specific ID.
     pub fn add_hidden_done(&self, id: ProgressEntryId, done: u32) {
-        let inner = &mut *self.inner.lock();
-        if let Some(p) = inner.entries.get_mut(&id) {
-            p.1.done += done;
-        } else {
-            inner.entries.insert(
-                id,
-                (Progress::default(), Progress { done, total: 0 }.into()),
-            );
-        }
-        inner.sum_entries.1.done += done;
+        self.inner.lock().update_entry(id, |p| {
+            p.1.done = p.1.done.saturating_add(done);
+        });
+    }
+
+    /// Overwrite the stored (visible) expected work items for a specific ID,
+    /// using a `u64` value.
+    ///
+    /// The `u64` methods are for work that can be larger than `u32::MAX`
+    /// units (such as downloading many gigabytes, counted in bytes). The
+    /// full `u64` values are stored separately (see
+    /// [`get_progress_u64`](Self::get_progress_u64)). The visible progress
+    /// of the entry, which is what everything else (including the global
+    /// progress) uses, is set to the same values scaled down to fit in a
+    /// `u32`. That means [`get_progress`](Self::get_progress) etc. may only
+    /// return an approximation for very large values. The entry is never
+    /// reported as complete before the work actually is.
+    ///
+    /// Do not mix these with the `u32` methods for the visible progress of
+    /// the same entry. The next `u64` update overwrites it.
+    pub fn set_total_u64(&self, id: ProgressEntryId, total: u64) {
+        self.inner.lock().update_wide_entry(id, |_, t| *t = total);
+    }
+
+    /// Overwrite the stored (visible) completed work items for a specific ID,
+    /// using a `u64` value.
+    ///
+    /// See [`set_total_u64`](Self::set_total_u64).
+    pub fn set_done_u64(&self, id: ProgressEntryId, done: u64) {
+        self.inner.lock().update_wide_entry(id, |d, _| *d = done);
+    }
+
+    /// Add more (visible) expected work items to the previously stored value
+    /// for a specific ID, using a `u64` value.
+    ///
+    /// See [`set_total_u64`](Self::set_total_u64).
+    pub fn add_total_u64(&self, id: ProgressEntryId, total: u64) {
+        self.inner.lock().update_wide_entry(id, |_, t| {
+            *t = t.saturating_add(total);
+        });
+    }
+
+    /// Add more (visible) completed work items to the previously stored value
+    /// for a specific ID, using a `u64` value.
+    ///
+    /// See [`set_total_u64`](Self::set_total_u64).
+    pub fn add_done_u64(&self, id: ProgressEntryId, done: u64) {
+        self.inner.lock().update_wide_entry(id, |d, _| {
+            *d = d.saturating_add(done);
+        });
+    }
+
+    /// Get the full `(done, total)` values stored using the `u64` methods
+    /// for a specific ID.
+    ///
+    /// Returns `None` if no `u64` values were stored for the ID.
+    pub fn get_progress_u64(&self, id: ProgressEntryId) -> Option<(u64, u64)> {
+        self.inner.lock().wide.get(&id).copied()
+    }
+}
+
+/// Read-only access to all the data stored in a [`ProgressTracker`].
+///
+/// See [`ProgressStore::read`].
+pub struct GlobalProgressView<'a> {
+    inner: &'a GlobalProgressTrackerInner,
+}
+
+impl GlobalProgressView<'_> {
+    /// Get the overall visible progress.
+    pub fn get_global_progress(&self) -> Progress {
+        self.inner.global_progress()
+    }
+
+    /// Get the overall hidden progress.
+    pub fn get_global_hidden_progress(&self) -> HiddenProgress {
+        self.inner.global_hidden_progress()
+    }
+
+    /// Get the overall visible+hidden progress.
+    pub fn get_global_combined_progress(&self) -> Progress {
+        self.inner.global_combined_progress()
+    }
+
+    /// Get the overall progress that should be shown to the user.
+    ///
+    /// See [`ProgressStore::get_displayed_progress`].
+    pub fn get_displayed_progress(&self) -> Progress {
+        self.inner.displayed_progress()
+    }
+
+    /// Check if all progress is complete.
+    pub fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Get the label of a specific ID, if it has one.
+    pub fn get_label(&self, id: ProgressEntryId) -> Option<&str> {
+        self.inner.labels.get(&id).map(|s| s.as_str())
+    }
+
+    /// Check if there is no work, some work in progress, or all work is
+    /// complete.
+    ///
+    /// See [`ProgressStore::completion_state`].
+    pub fn completion_state(&self) -> CompletionState {
+        self.inner.completion_state()
+    }
+
+    /// Get the number of entries stored in the tracker.
+    pub fn entry_count(&self) -> usize {
+        self.inner.entries.len()
+    }
+
+    /// Check if there is any progress data stored for a given ID.
+    pub fn contains_id(&self, id: ProgressEntryId) -> bool {
+        self.inner.entries.contains_key(&id)
+    }
+
+    /// Check if the progress for a specific ID is complete.
+    pub fn is_id_ready(&self, id: ProgressEntryId) -> bool {
+        self.inner.is_id_ready(id)
+    }
+
+    /// Check if a specific ID is waiting for any of its dependencies to be
+    /// ready.
+    ///
+    /// See [`ProgressStore::add_dependency`].
+    pub fn is_entry_blocked(&self, id: ProgressEntryId) -> bool {
+        self.inner.is_entry_blocked(id)
+    }
+
+    /// Check if any entry has an error.
+    pub fn has_errors(&self) -> bool {
+        !self.inner.errors.is_empty()
+    }
+
+    /// Get the error message of a specific ID, if it has an error.
+    pub fn get_error(&self, id: ProgressEntryId) -> Option<&str> {
+        self.inner.errors.get(&id).map(String::as_str)
+    }
+
+    /// Get the status message of a specific ID, if it has one.
+    pub fn get_status(&self, id: ProgressEntryId) -> Option<&str> {
+        self.inner.statuses.get(&id).map(String::as_str)
+    }
+
+    /// Get the visible progress stored for a specific ID.
+    pub fn get_progress(&self, id: ProgressEntryId) -> Progress {
+        self.inner.entries.get(&id).copied().unwrap_or_default().0
+    }
+
+    /// Get the hidden progress stored for a specific ID.
+    pub fn get_hidden_progress(&self, id: ProgressEntryId) -> HiddenProgress {
+        self.inner.entries.get(&id).copied().unwrap_or_default().1
+    }
+
+    /// Get the visible+hidden progress stored for a specific ID.
+    pub fn get_combined_progress(&self, id: ProgressEntryId) -> Progress {
+        self.inner.combined_progress(id).unwrap_or_default()
+    }
+
+    /// Iterate over all the entries stored in the tracker.
+    ///
+    /// Entries are sorted by [`ProgressEntryId`].
+    pub fn entries(
+        &self,
+    ) -> impl Iterator<Item = (ProgressEntryId, Progress, HiddenProgress)> + '_
+    {
+        self.inner.entries.iter().map(|(k, v)| (*k, v.0, v.1))
     }
 }
 
@@ -502,13 +1870,46 @@ impl Default for ProgressEntryIdWrapper {
 /// Each instance of this system param will create an entry in the
 /// [`ProgressTracker`] for itself and allow you to access the
 /// associated value. The ID is managed internally.
+///
+/// This also works in observers, to report progress in response to
+/// triggered events:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+/// # #[derive(Event)]
+/// # struct ItemLoaded;
+/// # let mut app = App::new();
+/// fn on_item_loaded(
+///     _trigger: Trigger<ItemLoaded>,
+///     progress: ProgressEntry<MyStates>,
+/// ) {
+///     progress.add_done(1);
+/// }
+///
+/// app.add_observer(on_item_loaded);
+/// ```
+///
+/// Keep in mind that the entry will not exist until the observer runs for
+/// the first time. If the total is known in advance, consider using a
+/// [`ProgressEntryId`] that you create yourself, together with
+/// [`ProgressPlugin::with_reservation`], and `Res<ProgressTracker<S>>`
+/// in your observer.
 #[derive(SystemParam)]
-pub struct ProgressEntry<'w, 's, S: FreelyMutableState> {
+pub struct ProgressEntry<'w, 's, S: ProgressContext> {
     global: Res<'w, ProgressTracker<S>>,
     my_id: Local<'s, ProgressEntryIdWrapper>,
+    budget: Option<Res<'w, ProgressBudget>>,
+    time: Option<Res<'w, Time>>,
 }
 
-impl<S: FreelyMutableState> ProgressEntry<'_, '_, S> {
+impl<S: ProgressContext> ProgressEntry<'_, '_, S> {
     /// Get the ID of the [`ProgressTracker`] entry managed by this system param
     pub fn id(&self) -> ProgressEntryId {
         self.my_id.0
@@ -539,16 +1940,133 @@ impl<S: FreelyMutableState> ProgressEntry<'_, '_, S> {
         self.global.is_ready()
     }
 
+    /// Get the overall visible progress as a fraction.
+    ///
+    /// See [`ProgressStore::global_fraction`].
+    pub fn global_fraction(&self) -> f32 {
+        self.global.global_fraction()
+    }
+
+    /// Get the overall visible progress as a percentage (`0..=100`),
+    /// capped at `99` until everything is ready.
+    ///
+    /// See [`ProgressStore::global_percent`].
+    pub fn global_percent(&self) -> u8 {
+        self.global.global_percent()
+    }
+
+    /// Get the visible progress associated with this system param as a
+    /// fraction.
+    ///
+    /// See [`ProgressStore::entry_fraction`].
+    pub fn fraction(&self) -> f32 {
+        self.global.entry_fraction(self.my_id.0)
+    }
+
+    /// Get the visible progress associated with this system param as a
+    /// percentage (`0..=100`), capped at `99` until it is ready.
+    ///
+    /// See [`ProgressStore::entry_percent`].
+    pub fn percent(&self) -> u8 {
+        self.global.entry_percent(self.my_id.0)
+    }
+
     /// Check if the progress associated with this system param is ready.
     pub fn is_ready(&self) -> bool {
         self.global.is_id_ready(self.my_id.0)
     }
 
+    /// Make the progress associated with this system param count more (or
+    /// less) towards the global progress.
+    ///
+    /// See [`ProgressStore::set_weight`].
+    pub fn set_weight(&self, weight: f32) {
+        self.global.set_weight(self.my_id.0, weight)
+    }
+
+    /// Remove the entry associated with this system param.
+    ///
+    /// See [`ProgressStore::remove_entry`]. If your system reports progress
+    /// again later, the entry is re-created.
+    pub fn remove(self) {
+        self.global.remove_entry(self.my_id.0)
+    }
+
+    /// Clear everything in the whole [`ProgressTracker<S>`], not just the
+    /// progress associated with this system param.
+    ///
+    /// Useful for a "restart loading" trigger. The entry for this system
+    /// param is cleared too, so report your progress again afterwards.
+    ///
+    /// See [`ProgressStore::clear`] for the details.
+    pub fn clear_all(&self) {
+        self.global.clear()
+    }
+
+    /// Set the status message associated with this system param.
+    ///
+    /// See [`ProgressStore::set_status`].
+    pub fn set_status(&self, status: impl Into<String>) {
+        self.global.set_status(self.my_id.0, status)
+    }
+
+    /// Remove the status message associated with this system param.
+    pub fn clear_status(&self) {
+        self.global.clear_status(self.my_id.0)
+    }
+
+    /// Get the status message associated with this system param.
+    pub fn get_status(&self) -> Option<String> {
+        self.global.get_status(self.my_id.0)
+    }
+
+    /// Give a human-readable name to the progress associated with this
+    /// system param.
+    ///
+    /// Labels are cleared together with everything else in the tracker,
+    /// so set it every time your system reports progress, or check
+    /// [`get_label`](Self::get_label) first:
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// fn load_map(pe: ProgressEntry<MyStates>) {
+    ///     if pe.get_label().is_none() {
+    ///         pe.set_label("load_map");
+    ///     }
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// See [`ProgressStore::set_label`].
+    pub fn set_label(&self, label: impl Into<String>) {
+        self.global.set_label(self.my_id.0, label)
+    }
+
+    /// Get the label associated with this system param, if it has one.
+    pub fn get_label(&self) -> Option<String> {
+        self.global.get_label(self.my_id.0)
+    }
+
     /// Get the visible+hidden progress associated with this system param.
     pub fn get_combined_progress(&self) -> Progress {
         self.global.get_combined_progress(self.my_id.0)
     }
 
+    /// Get both the visible and hidden progress associated with this system
+    /// param, consistently, under a single lock.
+    ///
+    /// See [`ProgressStore::get_entry`].
+    pub fn get_entry(&self) -> Option<(Progress, HiddenProgress)> {
+        self.global.get_entry(self.my_id.0)
+    }
+
     /// Get the visible progress associated with this system param.
     pub fn get_progress(&self) -> Progress {
         self.global.get_progress(self.my_id.0)
@@ -572,6 +2090,22 @@ impl<S: FreelyMutableState> ProgressEntry<'_, '_, S> {
         self.global.set_progress(self.my_id.0, done, total)
     }
 
+    /// Overwrite the visible progress associated with this system param,
+    /// from a completed fraction (`0.0..=1.0`).
+    ///
+    /// See [`Progress::from_fraction`].
+    pub fn set_fraction(&self, fraction: f32) {
+        self.global.set_fraction(self.my_id.0, fraction)
+    }
+
+    /// Overwrite both the visible and hidden progress associated with this
+    /// system param, at once.
+    ///
+    /// See [`ProgressStore::set_all`].
+    pub fn set_all(&self, visible: Progress, hidden: HiddenProgress) {
+        self.global.set_all(self.my_id.0, visible, hidden)
+    }
+
     /// Overwrite the (visible) expected work items associated with this system
     /// param.
     pub fn set_total(&self, total: u32) {
@@ -603,6 +2137,120 @@ impl<S: FreelyMutableState> ProgressEntry<'_, '_, S> {
         self.global.add_done(self.my_id.0, done)
     }
 
+    /// Report that one more item of (visible) work has been completed,
+    /// worth `weight` units of work.
+    ///
+    /// This is the same as [`add_done`](Self::add_done). It is meant for
+    /// work that is done in items of varying size (such as files to
+    /// decompress): set the total once (the sum of the weights of all
+    /// items), and then report each item as it is completed.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct MyFiles(Vec<MyFile>);
+    /// # struct MyFile {
+    /// #     size: u32,
+    /// # }
+    /// # impl MyFile {
+    /// #     fn decompress(&self) {}
+    /// # }
+    /// # impl MyFiles {
+    /// #     fn iter(&self) -> impl Iterator<Item = &MyFile> {
+    /// #         self.0.iter()
+    /// #     }
+    /// #     fn next_pending(&self) -> Option<&MyFile> {
+    /// #         self.0.first()
+    /// #     }
+    /// # }
+    /// fn decompress_files(pe: ProgressEntry<MyStates>, files: Res<MyFiles>) {
+    ///     if pe.get_total() == 0 {
+    ///         pe.set_total(files.iter().map(|f| f.size).sum());
+    ///     }
+    ///     if let Some(file) = files.next_pending() {
+    ///         file.decompress();
+    ///         pe.report_item(file.size);
+    ///     }
+    /// }
+    /// ```
+    pub fn report_item(&self, weight: u32) {
+        self.add_done(weight)
+    }
+
+    /// Declare that the work of this system param should not start until
+    /// the work of another ID is ready.
+    ///
+    /// See [`ProgressStore::add_dependency`].
+    pub fn depends_on(&self, other: ProgressEntryId) {
+        self.global.add_dependency(self.my_id.0, other)
+    }
+
+    /// Is this system param waiting for any of its dependencies to be ready?
+    ///
+    /// See [`ProgressStore::is_entry_blocked`].
+    pub fn is_blocked(&self) -> bool {
+        self.global.is_entry_blocked(self.my_id.0)
+    }
+
+    /// Get how many items of (visible) work to process in this frame.
+    ///
+    /// This is meant for systems that process many items over several
+    /// frames. The result is `per_frame`, reduced if there is a
+    /// [`ProgressBudget`] and the previous frame took too long, and never
+    /// more than the work that remains (`total - done`). Process that many
+    /// items and then report them using [`add_done`](Self::add_done).
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct Trees(Vec<MyTree>);
+    /// # struct MyTree;
+    /// # impl MyTree {
+    /// #     fn spawn(self) {}
+    /// # }
+    /// # impl Trees {
+    /// #     fn len(&self) -> u32 {
+    /// #         self.0.len() as u32
+    /// #     }
+    /// #     fn next_batch(&mut self, n: u32) -> Vec<MyTree> {
+    /// #         let n = (n as usize).min(self.0.len());
+    /// #         self.0.drain(..n).collect()
+    /// #     }
+    /// # }
+    /// fn spawn_trees(pe: ProgressEntry<MyStates>, mut trees: ResMut<Trees>) {
+    ///     if pe.get_total() == 0 {
+    ///         pe.set_total(trees.len());
+    ///     }
+    ///     let n = pe.take_budget(100);
+    ///     for tree in trees.next_batch(n) {
+    ///         tree.spawn();
+    ///     }
+    ///     pe.add_done(n);
+    /// }
+    /// ```
+    pub fn take_budget(&self, per_frame: u32) -> u32 {
+        let mut n = per_frame;
+        if let (Some(budget), Some(time)) = (&self.budget, &self.time) {
+            n = budget.scale(per_frame, time.delta());
+        }
+        let progress = self.get_progress();
+        n.min(progress.total.saturating_sub(progress.done))
+    }
+
     /// Get the hidden progress associated with this system param.
     pub fn get_hidden_progress(&self) -> HiddenProgress {
         self.global.get_hidden_progress(self.my_id.0)
@@ -657,40 +2305,278 @@ impl<S: FreelyMutableState> ProgressEntry<'_, '_, S> {
 }
 
 pub(crate) trait ApplyProgress: Sized {
-    fn apply_progress<S: FreelyMutableState>(
-        self,
-        tracker: &ProgressTracker<S>,
-        id: ProgressEntryId,
-    );
+    fn apply_progress(self, tracker: &ProgressStore, id: ProgressEntryId);
+    fn is_ready(&self) -> bool;
 }
 
 impl ApplyProgress for Progress {
-    fn apply_progress<S: FreelyMutableState>(
-        self,
-        tracker: &ProgressTracker<S>,
-        id: ProgressEntryId,
-    ) {
+    fn apply_progress(self, tracker: &ProgressStore, id: ProgressEntryId) {
         tracker.set_progress(id, self.done, self.total);
     }
+    fn is_ready(&self) -> bool {
+        Progress::is_ready(*self)
+    }
 }
 
 impl ApplyProgress for HiddenProgress {
-    fn apply_progress<S: FreelyMutableState>(
-        self,
-        tracker: &ProgressTracker<S>,
-        id: ProgressEntryId,
-    ) {
+    fn apply_progress(self, tracker: &ProgressStore, id: ProgressEntryId) {
         tracker.set_hidden_progress(id, self.0.done, self.0.total);
     }
+    fn is_ready(&self) -> bool {
+        self.0.is_ready()
+    }
 }
 
 impl<T1: ApplyProgress, T2: ApplyProgress> ApplyProgress for (T1, T2) {
-    fn apply_progress<S: FreelyMutableState>(
-        self,
-        tracker: &ProgressTracker<S>,
-        id: ProgressEntryId,
-    ) {
+    fn apply_progress(self, tracker: &ProgressStore, id: ProgressEntryId) {
         self.0.apply_progress(tracker, id);
         self.1.apply_progress(tracker, id);
     }
+    fn is_ready(&self) -> bool {
+        self.0.is_ready() && self.1.is_ready()
+    }
+}
+
+impl<T: ApplyProgress, E: std::fmt::Display> ApplyProgress for Result<T, E> {
+    fn apply_progress(self, tracker: &ProgressStore, id: ProgressEntryId) {
+        match self {
+            Ok(progress) => {
+                tracker.clear_error(id);
+                progress.apply_progress(tracker, id);
+            }
+            Err(e) => tracker.set_error(id, e.to_string()),
+        }
+    }
+    fn is_ready(&self) -> bool {
+        self.as_ref().is_ok_and(|p| p.is_ready())
+    }
+}
+
+pub(crate) fn sample_entry_eta<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+    time: Option<Res<Time<Virtual>>>,
+) {
+    if let Some(time) = time {
+        tracker.sample_eta(time.elapsed());
+    }
+}
+
+pub(crate) fn validate_progress<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+) {
+    if let Err(e) = tracker.inner.lock().validate() {
+        panic!(
+            "iyes_progress: ProgressTracker<{}> is inconsistent: {}",
+            std::any::type_name::<S>(),
+            e,
+        );
+    }
+}
+
+pub(crate) fn send_entry_complete_events<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+    mut evw: EventWriter<ProgressEntryComplete<S>>,
+) {
+    let newly = tracker.inner.lock().take_newly_complete();
+    evw.send_batch(newly.into_iter().map(|(id, label)| {
+        ProgressEntryComplete {
+            id,
+            label,
+            _pd: PhantomData,
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicates_with_saturated_sums() {
+        let store = ProgressStore::default();
+        for _ in 0..2 {
+            let id = store.new_id();
+            store.set_progress(id, u32::MAX, u32::MAX);
+            store.set_ready_predicate(id, |_| true);
+        }
+        assert!(store.is_ready());
+        let id = store.new_id();
+        store.set_progress(id, 0, 1);
+        assert!(!store.is_ready());
+    }
+
+    #[test]
+    fn predicate_on_missing_entry() {
+        let store = ProgressStore::default();
+        let id = store.new_id();
+        store.set_ready_predicate(id, |p| p.done >= 5);
+        assert!(!store.is_ready());
+        assert!(store.is_ready_except(id));
+        store.set_progress(id, 5, 10);
+        assert!(store.is_ready());
+    }
+
+    #[test]
+    fn weights_with_saturated_sums() {
+        let store = ProgressStore::default();
+        for _ in 0..2 {
+            let id = store.new_id();
+            store.set_progress(id, u32::MAX, u32::MAX);
+            store.set_weight(id, 2.0);
+        }
+        assert!(store.is_ready());
+        assert_eq!(store.global_fraction(), 1.0);
+    }
+
+    #[test]
+    fn weights_are_clamped() {
+        let store = ProgressStore::default();
+        let a = store.new_id();
+        let b = store.new_id();
+        store.set_weight(a, f32::INFINITY);
+        store.set_weight(b, f32::NEG_INFINITY);
+        assert_eq!(store.get_weight(a), f32::MAX);
+        assert_eq!(store.get_weight(b), 0.0);
+        store.set_weight(b, f32::NAN);
+        assert_eq!(store.get_weight(b), 0.0);
+
+        store.set_progress(a, 0, 4);
+        store.set_progress(b, 1, 2);
+        let fraction = store.global_fraction();
+        assert!(fraction.is_finite() && fraction < 1.0);
+        assert!(!store.is_ready());
+        store.set_done(a, 4);
+        assert!(!store.is_ready());
+        store.set_done(b, 2);
+        assert!(store.is_ready());
+    }
+
+    #[test]
+    fn except_with_saturated_sums() {
+        let store = ProgressStore::default();
+        let work = store.new_id();
+        let gate = store.new_id();
+        store.set_progress(work, u32::MAX, u32::MAX);
+        store.set_ready_predicate(work, |_| true);
+        store.set_progress(gate, u32::MAX, u32::MAX);
+        assert!(store.is_ready_except(gate));
+        let more = store.new_id();
+        store.set_progress(more, 0, 1);
+        assert!(!store.is_ready_except(gate));
+    }
+
+    #[test]
+    fn errors_with_saturated_sums() {
+        let store = ProgressStore::default();
+        let failed = store.new_id();
+        let gate = store.new_id();
+        store.set_progress(failed, u32::MAX, u32::MAX);
+        store.set_progress(gate, u32::MAX, u32::MAX);
+        store.set_error(failed, "oops");
+        assert!(!store.is_ready());
+        store.set_error_policy(ErrorPolicy::CountAsDone);
+        assert!(store.is_ready());
+        assert!(store.is_ready_except(gate));
+        store.set_progress(failed, 0, 1);
+        assert!(store.is_ready());
+    }
+
+    #[test]
+    fn random_operations_keep_sums_in_sync() {
+        // A simple xorshift, so that the test is deterministic.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let store = ProgressStore::default();
+        let ids: Vec<_> = (0..8).map(|_| store.new_id()).collect();
+        for _ in 0..10_000 {
+            let id = ids[next() as usize % ids.len()];
+            // Mostly small values, but sometimes huge ones, to saturate.
+            let value = |r: u64| match r % 4 {
+                0 => u32::MAX - (r >> 32) as u32 % 4,
+                _ => (r >> 32) as u32 % 100,
+            };
+            let (a, b) = (value(next()), value(next()));
+            match next() % 6 {
+                0 => store.set_progress(id, a, b),
+                1 => store.add_progress(id, a, b),
+                2 => store.set_hidden_progress(id, a, b),
+                3 => store.add_hidden_progress(id, a, b),
+                4 => store.set_done(id, a),
+                _ => store.remove_entry(id),
+            }
+            if next() % 3 == 0 {
+                store.set_ready_predicate(id, |p| p.done > 0);
+            }
+            if next() % 3 == 0 {
+                store.set_weight(id, (next() % 4) as f32);
+            }
+            let inner = store.inner.lock();
+            assert_eq!(inner.validate(), Ok(()));
+            let _ = inner.is_ready();
+            let _ = inner.is_ready_except(Some(id));
+            let _ = inner.global_progress();
+        }
+    }
+
+    #[test]
+    fn set_all_is_never_torn() {
+        let store = ProgressStore::default();
+        let id = store.new_id();
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for i in 0..10_000 {
+                    store.set_all(
+                        id,
+                        Progress::new(i, 10_000),
+                        HiddenProgress::new(i, 10_000),
+                    );
+                }
+            });
+            scope.spawn(|| {
+                for _ in 0..10_000 {
+                    let (v, h) = store.get_entry(id).unwrap_or_default();
+                    assert_eq!(v, h.0);
+                    let global = store.read(|view| {
+                        (
+                            view.get_global_progress(),
+                            view.get_global_hidden_progress(),
+                        )
+                    });
+                    assert_eq!(global.0, global.1 .0);
+                }
+            });
+        });
+        assert_eq!(store.get_progress(id), Progress::new(9_999, 10_000));
+    }
+
+    #[test]
+    fn fraction_and_percent_match_progress() {
+        let store = ProgressStore::default();
+        assert_eq!(store.global_fraction(), 1.0);
+        assert_eq!(store.global_percent(), 100);
+
+        let id = store.new_id();
+        for (done, total) in [(0, 1), (1, 3), (999, 1000), (5, 4)] {
+            let p = Progress::new(done, total);
+            store.set_progress(id, done, total);
+            assert_eq!(store.global_fraction(), p.fraction());
+            assert_eq!(store.entry_fraction(id), p.fraction());
+            assert_eq!(store.labeled_fractions(), [(None, p.fraction())]);
+            assert_eq!(store.global_percent(), p.percent());
+            assert_eq!(store.entry_percent(id), p.percent());
+        }
+        assert_eq!(store.global_percent(), 100);
+
+        // Visible progress is complete, but hidden work remains.
+        store.set_progress(id, 1, 1);
+        store.set_hidden_progress(id, 0, 1);
+        assert_eq!(store.global_fraction(), 1.0);
+        assert_eq!(store.global_percent(), 99);
+        assert_eq!(store.entry_percent(id), 99);
+    }
 }
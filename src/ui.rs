@@ -0,0 +1,214 @@
+use bevy_app::prelude::*;
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_state::prelude::*;
+use bevy_state::state::FreelyMutableState;
+use bevy_ui::prelude::*;
+
+use crate::prelude::*;
+
+/// A ready-made loading screen, for the common case of one loading state.
+///
+/// This plugin adds a [`ProgressPlugin<S>`] (configured to transition from
+/// the loading state to the next state, with asset tracking enabled), and
+/// spawns a simple progress bar while in the loading state.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use iyes_progress::prelude::*;
+/// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// # enum MyStates {
+/// #     #[default]
+/// #     Loading,
+/// #     Done,
+/// # }
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .init_state::<MyStates>()
+///         .add_plugins(
+///             LoadingScreenPlugin::<MyStates>::new(
+///                 MyStates::Loading,
+///                 MyStates::Done,
+///             ),
+///         )
+///         // ...
+///         .run();
+/// ```
+///
+/// You still need to add your assets to [`AssetsLoading<S>`] and track your
+/// other work, as usual. Your app must have a camera for the UI to be
+/// visible.
+///
+/// If you want different behavior, you can:
+///  - Configure the underlying [`ProgressPlugin<S>`] using
+///    [`with_progress_plugin`](Self::with_progress_plugin).
+///  - Change the colors of the bar.
+///  - Restyle the UI by querying for the [`LoadingScreenRoot`] and
+///    [`LoadingBarFill`] components.
+///  - Disable the default UI entirely using
+///    [`without_default_ui`](Self::without_default_ui) and make your own.
+pub struct LoadingScreenPlugin<S: ProgressContext> {
+    progress: ProgressPlugin<S>,
+    loading_state: S::State,
+    default_ui: bool,
+    bar_color: Color,
+    background_color: Color,
+}
+
+/// Marker for the root UI node of the default loading screen.
+#[derive(Component)]
+pub struct LoadingScreenRoot;
+
+/// Marker for the UI node that represents the filled part of the progress
+/// bar, in the default loading screen. Its width is updated every frame.
+#[derive(Component)]
+pub struct LoadingBarFill;
+
+impl<S: ProgressContext> LoadingScreenPlugin<S> {
+    /// Create a new instance of this plugin.
+    ///
+    /// While in the `loading` state, the loading screen will be displayed.
+    /// When all progress is complete, the state will transition to `next`.
+    pub fn new(loading: S::State, next: S::State) -> Self
+    where
+        S::State: FreelyMutableState,
+    {
+        Self {
+            progress: ProgressPlugin::new()
+                .with_state_transition(loading.clone(), next)
+                .with_asset_tracking(),
+            loading_state: loading,
+            default_ui: true,
+            bar_color: Color::srgb(0.75, 0.75, 0.75),
+            background_color: Color::srgb(0.25, 0.25, 0.25),
+        }
+    }
+
+    /// Customize the underlying [`ProgressPlugin<S>`].
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use iyes_progress::prelude::*;
+    /// # #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// # enum MyStates {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Done,
+    /// # }
+    /// # let _ =
+    /// LoadingScreenPlugin::<MyStates>::new(MyStates::Loading, MyStates::Done)
+    ///     .with_progress_plugin(|p| p.with_max_fill_rate(0.5))
+    /// # ;
+    /// ```
+    pub fn with_progress_plugin(
+        mut self,
+        f: impl FnOnce(ProgressPlugin<S>) -> ProgressPlugin<S>,
+    ) -> Self {
+        self.progress = f(self.progress);
+        self
+    }
+
+    /// Do not spawn the default loading screen UI.
+    ///
+    /// Use this if you want to make your own UI, but still want everything
+    /// else to be set up for you.
+    pub fn without_default_ui(mut self) -> Self {
+        self.default_ui = false;
+        self
+    }
+
+    /// Set the color of the filled part of the progress bar.
+    pub fn with_bar_color(mut self, color: impl Into<Color>) -> Self {
+        self.bar_color = color.into();
+        self
+    }
+
+    /// Set the color of the empty part of the progress bar.
+    pub fn with_background_color(mut self, color: impl Into<Color>) -> Self {
+        self.background_color = color.into();
+        self
+    }
+}
+
+impl<S: ProgressContext> Plugin for LoadingScreenPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(self.progress.clone());
+        if !self.default_ui {
+            return;
+        }
+        let bar_color = self.bar_color;
+        let background_color = self.background_color;
+        app.add_systems(
+            OnEnter(self.loading_state.clone()),
+            move |commands: Commands| {
+                spawn_loading_screen(commands, bar_color, background_color)
+            },
+        );
+        app.add_systems(
+            OnExit(self.loading_state.clone()),
+            despawn_loading_screen,
+        );
+        app.add_systems(
+            Update,
+            update_loading_bar::<S>
+                .run_if(in_state(self.loading_state.clone())),
+        );
+    }
+}
+
+fn spawn_loading_screen(
+    mut commands: Commands,
+    bar_color: Color,
+    background_color: Color,
+) {
+    commands
+        .spawn((
+            LoadingScreenRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(69.0),
+                bottom: Val::Percent(24.0),
+                left: Val::Percent(12.0),
+                right: Val::Percent(12.0),
+                ..Default::default()
+            },
+            BackgroundColor(background_color),
+            BorderRadius::all(Val::Px(8.0)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LoadingBarFill,
+                Node {
+                    height: Val::Percent(100.0),
+                    width: Val::Percent(0.0),
+                    ..Default::default()
+                },
+                BackgroundColor(bar_color),
+                BorderRadius::all(Val::Px(8.0)),
+            ));
+        });
+}
+
+fn despawn_loading_screen(
+    mut commands: Commands,
+    q: Query<Entity, With<LoadingScreenRoot>>,
+) {
+    for e in q.iter() {
+        commands.entity(e).despawn_recursive();
+    }
+}
+
+fn update_loading_bar<S: ProgressContext>(
+    tracker: Res<ProgressTracker<S>>,
+    displayed: Option<Res<DisplayedProgress<S>>>,
+    mut q: Query<&mut Node, With<LoadingBarFill>>,
+) {
+    let fraction = match displayed {
+        Some(displayed) => displayed.fraction(),
+        None => tracker.get_displayed_progress().fraction().min(1.0),
+    };
+    for mut node in q.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+    }
+}
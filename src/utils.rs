@@ -1,5 +1,6 @@
 use bevy_ecs::prelude::*;
-use bevy_utils::{Duration, Instant};
+use bevy_time::prelude::*;
+use bevy_utils::Duration;
 
 use crate::prelude::Progress;
 
@@ -35,12 +36,15 @@ pub fn dummy_system_count_frames<const N: u32>(
 
 /// Dummy system to wait for a time duration
 ///
+/// The time is measured using Bevy's virtual time ([`Time<Virtual>`]), so it
+/// respects pausing and speed scaling, and can be advanced manually in tests.
+///
 /// May be useful for testing/debug/workaround purposes.
 pub fn dummy_system_wait_millis<const MILLIS: u64>(
-    mut state: Local<Option<Instant>>,
+    time: Res<Time<Virtual>>,
+    mut state: Local<Option<Duration>>,
 ) -> Progress {
-    let end =
-        state.unwrap_or_else(|| Instant::now() + Duration::from_millis(MILLIS));
-    *state = Some(end);
-    (Instant::now() > end).into()
+    let end = *state
+        .get_or_insert_with(|| time.elapsed() + Duration::from_millis(MILLIS));
+    (time.elapsed() > end).into()
 }